@@ -61,6 +61,51 @@ func TestClosure(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestRefClosure(t *testing.T) {
+	src := `<? function &(){};`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    18,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    18,
+				},
+				Expr: &expr.Closure{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  3,
+						EndPos:    17,
+					},
+					ReturnsRef:    true,
+					Static:        false,
+					PhpDocComment: "",
+					Stmts:         []node.Node{},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+
+	php5parser := php5.NewParser([]byte(src), "5.6")
+	php5parser.Parse()
+	actual = php5parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
 func TestClosureUse(t *testing.T) {
 	src := `<? function($a, $b) use ($c, &$d) {};`
 
@@ -321,3 +321,228 @@ func TestNewAnonymous(t *testing.T) {
 	actual := php7parser.GetRootNode()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestNewStatic(t *testing.T) {
+	src := `<? new static();`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    16,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    16,
+				},
+				Expr: &expr.New{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  3,
+						EndPos:    15,
+					},
+					Class: &node.Identifier{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  7,
+							EndPos:    13,
+						},
+						Value: "static",
+					},
+					ArgumentList: &node.ArgumentList{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  13,
+							EndPos:    15,
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+
+	php5parser := php5.NewParser([]byte(src), "5.6")
+	php5parser.Parse()
+	actual = php5parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
+func TestNewVariable(t *testing.T) {
+	src := `<? new $foo($a);`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    16,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    16,
+				},
+				Expr: &expr.New{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  3,
+						EndPos:    15,
+					},
+					Class: &expr.Variable{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  7,
+							EndPos:    11,
+						},
+						VarName: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  7,
+								EndPos:    11,
+							},
+							Value: "foo",
+						},
+					},
+					ArgumentList: &node.ArgumentList{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  11,
+							EndPos:    15,
+						},
+						Arguments: []node.Node{
+							&node.Argument{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  12,
+									EndPos:    14,
+								},
+								Variadic:    false,
+								IsReference: false,
+								Expr: &expr.Variable{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  12,
+										EndPos:    14,
+									},
+									VarName: &node.Identifier{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  12,
+											EndPos:    14,
+										},
+										Value: "a",
+									},
+								},
+							},
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+
+	php5parser := php5.NewParser([]byte(src), "5.6")
+	php5parser.Parse()
+	actual = php5parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
+func TestPhp7NewPropertyFetch(t *testing.T) {
+	src := `<? (new Foo)->bar;`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  4,
+			EndPos:    18,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  4,
+					EndPos:    18,
+				},
+				Expr: &expr.PropertyFetch{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  4,
+						EndPos:    17,
+					},
+					Variable: &expr.New{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  4,
+							EndPos:    11,
+						},
+						Class: &name.Name{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  8,
+								EndPos:    11,
+							},
+							Parts: []node.Node{
+								&name.NamePart{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  8,
+										EndPos:    11,
+									},
+									Value: "Foo",
+								},
+							},
+						},
+					},
+					Property: &node.Identifier{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  14,
+							EndPos:    17,
+						},
+						Value: "bar",
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
@@ -302,3 +302,117 @@ func TestShortListList(t *testing.T) {
 	actual := php7parser.GetRootNode()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestShortListWithEmptyItem(t *testing.T) {
+	src := `<? [$a, , $c] = $b;`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    19,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    19,
+				},
+				Expr: &assign.Assign{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  3,
+						EndPos:    18,
+					},
+					Variable: &expr.ShortList{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  3,
+							EndPos:    13,
+						},
+						Items: []node.Node{
+							&expr.ArrayItem{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  4,
+									EndPos:    6,
+								},
+								Val: &expr.Variable{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  4,
+										EndPos:    6,
+									},
+									VarName: &node.Identifier{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  4,
+											EndPos:    6,
+										},
+										Value: "a",
+									},
+								},
+							},
+							&expr.ArrayItem{},
+							&expr.ArrayItem{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  10,
+									EndPos:    12,
+								},
+								Val: &expr.Variable{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  10,
+										EndPos:    12,
+									},
+									VarName: &node.Identifier{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  10,
+											EndPos:    12,
+										},
+										Value: "c",
+									},
+								},
+							},
+						},
+					},
+					Expression: &expr.Variable{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  16,
+							EndPos:    18,
+						},
+						VarName: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  16,
+								EndPos:    18,
+							},
+							Value: "b",
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
@@ -334,3 +334,232 @@ CAD;
 	actual = php5parser.GetRootNode()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestPhp7HeredocCurlyOpenVariable(t *testing.T) {
+	src := `<? <<<LBL
+{$a->b['k']}
+LBL;
+`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   3,
+			StartPos:  3,
+			EndPos:    27,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   3,
+					StartPos:  3,
+					EndPos:    27,
+				},
+				Expr: &scalar.Heredoc{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   3,
+						StartPos:  3,
+						EndPos:    26,
+					},
+					Label: "<<<LBL\n",
+					Parts: []node.Node{
+						&expr.ArrayDimFetch{
+							Position: &position.Position{
+								StartLine: 2,
+								EndLine:   2,
+								StartPos:  11,
+								EndPos:    21,
+							},
+							Variable: &expr.PropertyFetch{
+								Position: &position.Position{
+									StartLine: 2,
+									EndLine:   2,
+									StartPos:  11,
+									EndPos:    16,
+								},
+								Variable: &expr.Variable{
+									Position: &position.Position{
+										StartLine: 2,
+										EndLine:   2,
+										StartPos:  11,
+										EndPos:    13,
+									},
+									VarName: &node.Identifier{
+										Position: &position.Position{
+											StartLine: 2,
+											EndLine:   2,
+											StartPos:  11,
+											EndPos:    13,
+										},
+										Value: "a",
+									},
+								},
+								Property: &node.Identifier{
+									Position: &position.Position{
+										StartLine: 2,
+										EndLine:   2,
+										StartPos:  15,
+										EndPos:    16,
+									},
+									Value: "b",
+								},
+							},
+							Dim: &scalar.String{
+								Position: &position.Position{
+									StartLine: 2,
+									EndLine:   2,
+									StartPos:  17,
+									EndPos:    20,
+								},
+								Value: "'k'",
+							},
+						},
+						&scalar.EncapsedStringPart{
+							Position: &position.Position{
+								StartLine: 2,
+								EndLine:   2,
+								StartPos:  22,
+								EndPos:    23,
+							},
+							Value: "\n",
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
+func TestPhp7HeredocCurlyOpenMethodCall(t *testing.T) {
+	src := `<? <<<LBL
+{$obj->prop['x']->method()}
+LBL;
+`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   3,
+			StartPos:  3,
+			EndPos:    42,
+		},
+		Stmts: []node.Node{
+			&stmt.Expression{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   3,
+					StartPos:  3,
+					EndPos:    42,
+				},
+				Expr: &scalar.Heredoc{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   3,
+						StartPos:  3,
+						EndPos:    41,
+					},
+					Label: "<<<LBL\n",
+					Parts: []node.Node{
+						&expr.MethodCall{
+							Position: &position.Position{
+								StartLine: 2,
+								EndLine:   2,
+								StartPos:  11,
+								EndPos:    36,
+							},
+							Variable: &expr.ArrayDimFetch{
+								Position: &position.Position{
+									StartLine: 2,
+									EndLine:   2,
+									StartPos:  11,
+									EndPos:    26,
+								},
+								Variable: &expr.PropertyFetch{
+									Position: &position.Position{
+										StartLine: 2,
+										EndLine:   2,
+										StartPos:  11,
+										EndPos:    21,
+									},
+									Variable: &expr.Variable{
+										Position: &position.Position{
+											StartLine: 2,
+											EndLine:   2,
+											StartPos:  11,
+											EndPos:    15,
+										},
+										VarName: &node.Identifier{
+											Position: &position.Position{
+												StartLine: 2,
+												EndLine:   2,
+												StartPos:  11,
+												EndPos:    15,
+											},
+											Value: "obj",
+										},
+									},
+									Property: &node.Identifier{
+										Position: &position.Position{
+											StartLine: 2,
+											EndLine:   2,
+											StartPos:  17,
+											EndPos:    21,
+										},
+										Value: "prop",
+									},
+								},
+								Dim: &scalar.String{
+									Position: &position.Position{
+										StartLine: 2,
+										EndLine:   2,
+										StartPos:  22,
+										EndPos:    25,
+									},
+									Value: "'x'",
+								},
+							},
+							Method: &node.Identifier{
+								Position: &position.Position{
+									StartLine: 2,
+									EndLine:   2,
+									StartPos:  28,
+									EndPos:    34,
+								},
+								Value: "method",
+							},
+							ArgumentList: &node.ArgumentList{
+								Position: &position.Position{
+									StartLine: 2,
+									EndLine:   2,
+									StartPos:  34,
+									EndPos:    36,
+								},
+							},
+						},
+						&scalar.EncapsedStringPart{
+							Position: &position.Position{
+								StartLine: 2,
+								EndLine:   2,
+								StartPos:  37,
+								EndPos:    38,
+							},
+							Value: "\n",
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
@@ -129,6 +129,103 @@ func TestClassConstList(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestPhp7ClassConstListFinal(t *testing.T) {
+	src := `<? class foo{ final public const FOO = 1; }`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    43,
+		},
+		Stmts: []node.Node{
+			&stmt.Class{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    43,
+				},
+				PhpDocComment: "",
+				ClassName: &node.Identifier{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  9,
+						EndPos:    12,
+					},
+					Value: "foo",
+				},
+				Stmts: []node.Node{
+					&stmt.ClassConstList{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  14,
+							EndPos:    41,
+						},
+						Modifiers: []node.Node{
+							&node.Identifier{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  14,
+									EndPos:    19,
+								},
+								Value: "final",
+							},
+							&node.Identifier{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  20,
+									EndPos:    26,
+								},
+								Value: "public",
+							},
+						},
+						Consts: []node.Node{
+							&stmt.Constant{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  33,
+									EndPos:    40,
+								},
+								PhpDocComment: "",
+								ConstantName: &node.Identifier{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  33,
+										EndPos:    36,
+									},
+									Value: "FOO",
+								},
+								Expr: &scalar.Lnumber{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  39,
+										EndPos:    40,
+									},
+									Value: "1",
+								},
+							},
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
 func TestClassConstListWithoutModifiers(t *testing.T) {
 	src := `<? class foo{ const FOO = 1, BAR = 2; }`
 
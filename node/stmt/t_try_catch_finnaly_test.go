@@ -236,6 +236,122 @@ func TestPhp7TryCatch(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestPhp7TryCatchThreeTypes(t *testing.T) {
+	src := `<? try {} catch (A | B | C $e) {}`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    33,
+		},
+		Stmts: []node.Node{
+			&stmt.Try{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    33,
+				},
+				Stmts: []node.Node{},
+				Catches: []node.Node{
+					&stmt.Catch{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  10,
+							EndPos:    33,
+						},
+						Types: []node.Node{
+							&name.Name{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  17,
+									EndPos:    18,
+								},
+								Parts: []node.Node{
+									&name.NamePart{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  17,
+											EndPos:    18,
+										},
+										Value: "A",
+									},
+								},
+							},
+							&name.Name{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  21,
+									EndPos:    22,
+								},
+								Parts: []node.Node{
+									&name.NamePart{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  21,
+											EndPos:    22,
+										},
+										Value: "B",
+									},
+								},
+							},
+							&name.Name{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  25,
+									EndPos:    26,
+								},
+								Parts: []node.Node{
+									&name.NamePart{
+										Position: &position.Position{
+											StartLine: 1,
+											EndLine:   1,
+											StartPos:  25,
+											EndPos:    26,
+										},
+										Value: "C",
+									},
+								},
+							},
+						},
+						Variable: &expr.Variable{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  27,
+								EndPos:    29,
+							},
+							VarName: &node.Identifier{
+								Position: &position.Position{
+									StartLine: 1,
+									EndLine:   1,
+									StartPos:  27,
+									EndPos:    29,
+								},
+								Value: "e",
+							},
+						},
+						Stmts: []node.Node{},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
+
 func TestTryCatchCatch(t *testing.T) {
 	src := `<? 
 		try {} catch (Exception $e) {} catch (RuntimeException $e) {}
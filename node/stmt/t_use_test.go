@@ -1383,3 +1383,196 @@ func TestMixedGroupUse(t *testing.T) {
 	actual := php7parser.GetRootNode()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestMixedGroupUseWithAliases(t *testing.T) {
+	src := `<? use Foo\{Bar, Baz as Quux, function foo as bar, const BAR};`
+
+	expected := &node.Root{
+		Position: &position.Position{
+			StartLine: 1,
+			EndLine:   1,
+			StartPos:  3,
+			EndPos:    62,
+		},
+		Stmts: []node.Node{
+			&stmt.GroupUse{
+				Position: &position.Position{
+					StartLine: 1,
+					EndLine:   1,
+					StartPos:  3,
+					EndPos:    62,
+				},
+				Prefix: &name.Name{
+					Position: &position.Position{
+						StartLine: 1,
+						EndLine:   1,
+						StartPos:  7,
+						EndPos:    10,
+					},
+					Parts: []node.Node{
+						&name.NamePart{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  7,
+								EndPos:    10,
+							},
+							Value: "Foo",
+						},
+					},
+				},
+				UseList: []node.Node{
+					&stmt.Use{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  12,
+							EndPos:    15,
+						},
+						Use: &name.Name{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  12,
+								EndPos:    15,
+							},
+							Parts: []node.Node{
+								&name.NamePart{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  12,
+										EndPos:    15,
+									},
+									Value: "Bar",
+								},
+							},
+						},
+					},
+					&stmt.Use{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  17,
+							EndPos:    28,
+						},
+						Use: &name.Name{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  17,
+								EndPos:    20,
+							},
+							Parts: []node.Node{
+								&name.NamePart{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  17,
+										EndPos:    20,
+									},
+									Value: "Baz",
+								},
+							},
+						},
+						Alias: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  24,
+								EndPos:    28,
+							},
+							Value: "Quux",
+						},
+					},
+					&stmt.Use{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  39,
+							EndPos:    49,
+						},
+						UseType: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  30,
+								EndPos:    38,
+							},
+							Value: "function",
+						},
+						Use: &name.Name{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  39,
+								EndPos:    42,
+							},
+							Parts: []node.Node{
+								&name.NamePart{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  39,
+										EndPos:    42,
+									},
+									Value: "foo",
+								},
+							},
+						},
+						Alias: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  46,
+								EndPos:    49,
+							},
+							Value: "bar",
+						},
+					},
+					&stmt.Use{
+						Position: &position.Position{
+							StartLine: 1,
+							EndLine:   1,
+							StartPos:  57,
+							EndPos:    60,
+						},
+						UseType: &node.Identifier{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  51,
+								EndPos:    56,
+							},
+							Value: "const",
+						},
+						Use: &name.Name{
+							Position: &position.Position{
+								StartLine: 1,
+								EndLine:   1,
+								StartPos:  57,
+								EndPos:    60,
+							},
+							Parts: []node.Node{
+								&name.NamePart{
+									Position: &position.Position{
+										StartLine: 1,
+										EndLine:   1,
+										StartPos:  57,
+										EndPos:    60,
+									},
+									Value: "BAR",
+								},
+							},
+						},
+					},
+				},
+			},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetRootNode()
+	assert.DeepEqual(t, expected, actual)
+}
@@ -350,7 +350,7 @@ const yyEofCode = 1
 const yyErrCode = 2
 const yyInitialStackSize = 16
 
-// line php5/php5.y:7200
+// line php5/php5.y:7245
 
 type simpleIndirectReference struct {
 	all  []*expr.Variable
@@ -5702,11 +5702,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 234:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:3466
+		// line php5/php5.y:3469
 		{
 			yyVAL.node = assign.NewReference(yyDollar[1].node, yyDollar[4].node)
 
@@ -5718,11 +5721,14 @@ yydefault:
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Equal, yyDollar[3].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 235:
 		yyDollar = yyS[yypt-6 : yypt+1]
-		// line php5/php5.y:3480
+		// line php5/php5.y:3486
 		{
 			var _new *expr.New
 
@@ -5747,11 +5753,14 @@ yydefault:
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Equal, yyDollar[3].token.FreeFloating)
 			yylex.(*Parser).setFreeFloating(_new, freefloating.Start, yyDollar[4].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 236:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3507
+		// line php5/php5.y:3516
 		{
 			yyVAL.node = expr.NewClone(yyDollar[2].node)
 
@@ -5765,7 +5774,7 @@ yydefault:
 		}
 	case 237:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3519
+		// line php5/php5.y:3528
 		{
 			yyVAL.node = assign.NewPlus(yyDollar[1].node, yyDollar[3].node)
 
@@ -5775,11 +5784,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 238:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3531
+		// line php5/php5.y:3543
 		{
 			yyVAL.node = assign.NewMinus(yyDollar[1].node, yyDollar[3].node)
 
@@ -5790,11 +5802,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 239:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3544
+		// line php5/php5.y:3559
 		{
 			yyVAL.node = assign.NewMul(yyDollar[1].node, yyDollar[3].node)
 
@@ -5805,11 +5820,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 240:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3557
+		// line php5/php5.y:3575
 		{
 			yyVAL.node = assign.NewPow(yyDollar[1].node, yyDollar[3].node)
 
@@ -5820,11 +5838,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 241:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3570
+		// line php5/php5.y:3591
 		{
 			yyVAL.node = assign.NewDiv(yyDollar[1].node, yyDollar[3].node)
 
@@ -5835,11 +5856,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 242:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3583
+		// line php5/php5.y:3607
 		{
 			yyVAL.node = assign.NewConcat(yyDollar[1].node, yyDollar[3].node)
 
@@ -5850,11 +5874,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 243:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3596
+		// line php5/php5.y:3623
 		{
 			yyVAL.node = assign.NewMod(yyDollar[1].node, yyDollar[3].node)
 
@@ -5865,11 +5892,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 244:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3609
+		// line php5/php5.y:3639
 		{
 			yyVAL.node = assign.NewBitwiseAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -5880,11 +5910,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 245:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3622
+		// line php5/php5.y:3655
 		{
 			yyVAL.node = assign.NewBitwiseOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -5895,11 +5928,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 246:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3635
+		// line php5/php5.y:3671
 		{
 			yyVAL.node = assign.NewBitwiseXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -5910,11 +5946,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 247:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3648
+		// line php5/php5.y:3687
 		{
 			yyVAL.node = assign.NewShiftLeft(yyDollar[1].node, yyDollar[3].node)
 
@@ -5925,11 +5964,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 248:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3661
+		// line php5/php5.y:3703
 		{
 			yyVAL.node = assign.NewShiftRight(yyDollar[1].node, yyDollar[3].node)
 
@@ -5940,11 +5982,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 249:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3674
+		// line php5/php5.y:3719
 		{
 			yyVAL.node = expr.NewPostInc(yyDollar[1].node)
 
@@ -5959,7 +6004,7 @@ yydefault:
 		}
 	case 250:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3687
+		// line php5/php5.y:3732
 		{
 			yyVAL.node = expr.NewPreInc(yyDollar[2].node)
 
@@ -5973,7 +6018,7 @@ yydefault:
 		}
 	case 251:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3699
+		// line php5/php5.y:3744
 		{
 			yyVAL.node = expr.NewPostDec(yyDollar[1].node)
 
@@ -5988,7 +6033,7 @@ yydefault:
 		}
 	case 252:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3712
+		// line php5/php5.y:3757
 		{
 			yyVAL.node = expr.NewPreDec(yyDollar[2].node)
 
@@ -6002,7 +6047,7 @@ yydefault:
 		}
 	case 253:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3724
+		// line php5/php5.y:3769
 		{
 			yyVAL.node = binary.NewBooleanOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -6017,7 +6062,7 @@ yydefault:
 		}
 	case 254:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3737
+		// line php5/php5.y:3782
 		{
 			yyVAL.node = binary.NewBooleanAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -6032,7 +6077,7 @@ yydefault:
 		}
 	case 255:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3750
+		// line php5/php5.y:3795
 		{
 			yyVAL.node = binary.NewLogicalOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -6047,7 +6092,7 @@ yydefault:
 		}
 	case 256:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3763
+		// line php5/php5.y:3808
 		{
 			yyVAL.node = binary.NewLogicalAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -6062,7 +6107,7 @@ yydefault:
 		}
 	case 257:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3776
+		// line php5/php5.y:3821
 		{
 			yyVAL.node = binary.NewLogicalXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -6077,7 +6122,7 @@ yydefault:
 		}
 	case 258:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3789
+		// line php5/php5.y:3834
 		{
 			yyVAL.node = binary.NewBitwiseOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -6092,7 +6137,7 @@ yydefault:
 		}
 	case 259:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3802
+		// line php5/php5.y:3847
 		{
 			yyVAL.node = binary.NewBitwiseAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -6107,7 +6152,7 @@ yydefault:
 		}
 	case 260:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3815
+		// line php5/php5.y:3860
 		{
 			yyVAL.node = binary.NewBitwiseXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -6122,7 +6167,7 @@ yydefault:
 		}
 	case 261:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3828
+		// line php5/php5.y:3873
 		{
 			yyVAL.node = binary.NewConcat(yyDollar[1].node, yyDollar[3].node)
 
@@ -6137,7 +6182,7 @@ yydefault:
 		}
 	case 262:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3841
+		// line php5/php5.y:3886
 		{
 			yyVAL.node = binary.NewPlus(yyDollar[1].node, yyDollar[3].node)
 
@@ -6152,7 +6197,7 @@ yydefault:
 		}
 	case 263:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3854
+		// line php5/php5.y:3899
 		{
 			yyVAL.node = binary.NewMinus(yyDollar[1].node, yyDollar[3].node)
 
@@ -6167,7 +6212,7 @@ yydefault:
 		}
 	case 264:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3867
+		// line php5/php5.y:3912
 		{
 			yyVAL.node = binary.NewMul(yyDollar[1].node, yyDollar[3].node)
 
@@ -6182,7 +6227,7 @@ yydefault:
 		}
 	case 265:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3880
+		// line php5/php5.y:3925
 		{
 			yyVAL.node = binary.NewPow(yyDollar[1].node, yyDollar[3].node)
 
@@ -6197,7 +6242,7 @@ yydefault:
 		}
 	case 266:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3893
+		// line php5/php5.y:3938
 		{
 			yyVAL.node = binary.NewDiv(yyDollar[1].node, yyDollar[3].node)
 
@@ -6212,7 +6257,7 @@ yydefault:
 		}
 	case 267:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3906
+		// line php5/php5.y:3951
 		{
 			yyVAL.node = binary.NewMod(yyDollar[1].node, yyDollar[3].node)
 
@@ -6227,7 +6272,7 @@ yydefault:
 		}
 	case 268:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3919
+		// line php5/php5.y:3964
 		{
 			yyVAL.node = binary.NewShiftLeft(yyDollar[1].node, yyDollar[3].node)
 
@@ -6242,7 +6287,7 @@ yydefault:
 		}
 	case 269:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3932
+		// line php5/php5.y:3977
 		{
 			yyVAL.node = binary.NewShiftRight(yyDollar[1].node, yyDollar[3].node)
 
@@ -6257,7 +6302,7 @@ yydefault:
 		}
 	case 270:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3945
+		// line php5/php5.y:3990
 		{
 			yyVAL.node = expr.NewUnaryPlus(yyDollar[2].node)
 
@@ -6271,7 +6316,7 @@ yydefault:
 		}
 	case 271:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3957
+		// line php5/php5.y:4002
 		{
 			yyVAL.node = expr.NewUnaryMinus(yyDollar[2].node)
 
@@ -6285,7 +6330,7 @@ yydefault:
 		}
 	case 272:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3969
+		// line php5/php5.y:4014
 		{
 			yyVAL.node = expr.NewBooleanNot(yyDollar[2].node)
 
@@ -6299,7 +6344,7 @@ yydefault:
 		}
 	case 273:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:3981
+		// line php5/php5.y:4026
 		{
 			yyVAL.node = expr.NewBitwiseNot(yyDollar[2].node)
 
@@ -6313,7 +6358,7 @@ yydefault:
 		}
 	case 274:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:3993
+		// line php5/php5.y:4038
 		{
 			yyVAL.node = binary.NewIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -6328,7 +6373,7 @@ yydefault:
 		}
 	case 275:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4006
+		// line php5/php5.y:4051
 		{
 			yyVAL.node = binary.NewNotIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -6343,7 +6388,7 @@ yydefault:
 		}
 	case 276:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4019
+		// line php5/php5.y:4064
 		{
 			yyVAL.node = binary.NewEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6358,7 +6403,7 @@ yydefault:
 		}
 	case 277:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4032
+		// line php5/php5.y:4077
 		{
 			yyVAL.node = binary.NewNotEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6374,7 +6419,7 @@ yydefault:
 		}
 	case 278:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4046
+		// line php5/php5.y:4091
 		{
 			yyVAL.node = binary.NewSmaller(yyDollar[1].node, yyDollar[3].node)
 
@@ -6389,7 +6434,7 @@ yydefault:
 		}
 	case 279:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4059
+		// line php5/php5.y:4104
 		{
 			yyVAL.node = binary.NewSmallerOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6404,7 +6449,7 @@ yydefault:
 		}
 	case 280:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4072
+		// line php5/php5.y:4117
 		{
 			yyVAL.node = binary.NewGreater(yyDollar[1].node, yyDollar[3].node)
 
@@ -6419,7 +6464,7 @@ yydefault:
 		}
 	case 281:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4085
+		// line php5/php5.y:4130
 		{
 			yyVAL.node = binary.NewGreaterOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6434,7 +6479,7 @@ yydefault:
 		}
 	case 282:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4098
+		// line php5/php5.y:4143
 		{
 			yyVAL.node = expr.NewInstanceOf(yyDollar[1].node, yyDollar[3].node)
 
@@ -6449,7 +6494,7 @@ yydefault:
 		}
 	case 283:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4111
+		// line php5/php5.y:4156
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6462,7 +6507,7 @@ yydefault:
 		}
 	case 284:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4120
+		// line php5/php5.y:4165
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6470,7 +6515,7 @@ yydefault:
 		}
 	case 285:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4126
+		// line php5/php5.y:4171
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -6504,7 +6549,7 @@ yydefault:
 		}
 	case 286:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php5/php5.y:4158
+		// line php5/php5.y:4203
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, yyDollar[3].node, yyDollar[5].node)
 
@@ -6520,7 +6565,7 @@ yydefault:
 		}
 	case 287:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4172
+		// line php5/php5.y:4217
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, nil, yyDollar[4].node)
 
@@ -6536,7 +6581,7 @@ yydefault:
 		}
 	case 288:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4186
+		// line php5/php5.y:4231
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6544,7 +6589,7 @@ yydefault:
 		}
 	case 289:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4192
+		// line php5/php5.y:4237
 		{
 			yyVAL.node = cast.NewInt(yyDollar[2].node)
 
@@ -6559,7 +6604,7 @@ yydefault:
 		}
 	case 290:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4205
+		// line php5/php5.y:4250
 		{
 			yyVAL.node = cast.NewDouble(yyDollar[2].node)
 
@@ -6574,7 +6619,7 @@ yydefault:
 		}
 	case 291:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4218
+		// line php5/php5.y:4263
 		{
 			yyVAL.node = cast.NewString(yyDollar[2].node)
 
@@ -6589,7 +6634,7 @@ yydefault:
 		}
 	case 292:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4231
+		// line php5/php5.y:4276
 		{
 			yyVAL.node = cast.NewArray(yyDollar[2].node)
 
@@ -6604,7 +6649,7 @@ yydefault:
 		}
 	case 293:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4244
+		// line php5/php5.y:4289
 		{
 			yyVAL.node = cast.NewObject(yyDollar[2].node)
 
@@ -6619,7 +6664,7 @@ yydefault:
 		}
 	case 294:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4257
+		// line php5/php5.y:4302
 		{
 			yyVAL.node = cast.NewBool(yyDollar[2].node)
 
@@ -6634,7 +6679,7 @@ yydefault:
 		}
 	case 295:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4270
+		// line php5/php5.y:4315
 		{
 			yyVAL.node = cast.NewUnset(yyDollar[2].node)
 
@@ -6649,7 +6694,7 @@ yydefault:
 		}
 	case 296:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4283
+		// line php5/php5.y:4328
 		{
 			e := yyDollar[2].node.(*expr.Exit)
 			yyVAL.node = yyDollar[2].node
@@ -6672,7 +6717,7 @@ yydefault:
 		}
 	case 297:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4304
+		// line php5/php5.y:4349
 		{
 			yyVAL.node = expr.NewErrorSuppress(yyDollar[2].node)
 
@@ -6686,7 +6731,7 @@ yydefault:
 		}
 	case 298:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4316
+		// line php5/php5.y:4361
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6694,7 +6739,7 @@ yydefault:
 		}
 	case 299:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4322
+		// line php5/php5.y:4367
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6702,7 +6747,7 @@ yydefault:
 		}
 	case 300:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4328
+		// line php5/php5.y:4373
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6710,7 +6755,7 @@ yydefault:
 		}
 	case 301:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4334
+		// line php5/php5.y:4379
 		{
 			yyVAL.node = expr.NewShellExec(yyDollar[2].list)
 
@@ -6724,7 +6769,7 @@ yydefault:
 		}
 	case 302:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4346
+		// line php5/php5.y:4391
 		{
 			yyVAL.node = expr.NewPrint(yyDollar[2].node)
 
@@ -6738,7 +6783,7 @@ yydefault:
 		}
 	case 303:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4358
+		// line php5/php5.y:4403
 		{
 			yyVAL.node = expr.NewYield(nil, nil)
 
@@ -6752,7 +6797,7 @@ yydefault:
 		}
 	case 304:
 		yyDollar = yyS[yypt-9 : yypt+1]
-		// line php5/php5.y:4370
+		// line php5/php5.y:4415
 		{
 			yyVAL.node = expr.NewClosure(yyDollar[4].list, yyDollar[6].ClosureUse, nil, yyDollar[8].list, false, yyDollar[2].token != nil, "")
 
@@ -6781,7 +6826,7 @@ yydefault:
 		}
 	case 305:
 		yyDollar = yyS[yypt-10 : yypt+1]
-		// line php5/php5.y:4396
+		// line php5/php5.y:4441
 		{
 			yyVAL.node = expr.NewClosure(yyDollar[5].list, yyDollar[7].ClosureUse, nil, yyDollar[9].list, true, yyDollar[3].token != nil, "")
 
@@ -6811,7 +6856,7 @@ yydefault:
 		}
 	case 306:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4426
+		// line php5/php5.y:4471
 		{
 			yyVAL.node = expr.NewYield(nil, yyDollar[2].node)
 
@@ -6825,7 +6870,7 @@ yydefault:
 		}
 	case 307:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4438
+		// line php5/php5.y:4483
 		{
 			yyVAL.node = expr.NewYield(nil, yyDollar[2].node)
 
@@ -6839,7 +6884,7 @@ yydefault:
 		}
 	case 308:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4450
+		// line php5/php5.y:4495
 		{
 			yyVAL.node = expr.NewYield(yyDollar[2].node, yyDollar[4].node)
 
@@ -6854,7 +6899,7 @@ yydefault:
 		}
 	case 309:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4463
+		// line php5/php5.y:4508
 		{
 			yyVAL.node = expr.NewYield(yyDollar[2].node, yyDollar[4].node)
 
@@ -6869,7 +6914,7 @@ yydefault:
 		}
 	case 310:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4479
+		// line php5/php5.y:4524
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -6885,7 +6930,7 @@ yydefault:
 		}
 	case 311:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4493
+		// line php5/php5.y:4538
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -6901,7 +6946,7 @@ yydefault:
 		}
 	case 312:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4507
+		// line php5/php5.y:4552
 		{
 			str := scalar.NewString(yyDollar[1].token.Value)
 			yyVAL.node = expr.NewArrayDimFetch(str, yyDollar[3].node)
@@ -6919,7 +6964,7 @@ yydefault:
 		}
 	case 313:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4523
+		// line php5/php5.y:4568
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -6935,7 +6980,7 @@ yydefault:
 		}
 	case 314:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4540
+		// line php5/php5.y:4585
 		{
 			yyVAL.node = expr.NewArray(yyDollar[3].list)
 
@@ -6951,7 +6996,7 @@ yydefault:
 		}
 	case 315:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4554
+		// line php5/php5.y:4599
 		{
 			yyVAL.node = expr.NewShortArray(yyDollar[2].list)
 
@@ -6966,13 +7011,13 @@ yydefault:
 		}
 	case 316:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4570
+		// line php5/php5.y:4615
 		{
 			yyVAL.token = yyDollar[1].token
 		}
 	case 317:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:4577
+		// line php5/php5.y:4622
 		{
 			yyVAL.ClosureUse = nil
 
@@ -6980,7 +7025,7 @@ yydefault:
 		}
 	case 318:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4583
+		// line php5/php5.y:4628
 		{
 			yyVAL.ClosureUse = expr.NewClosureUse(yyDollar[3].list)
 
@@ -6996,7 +7041,7 @@ yydefault:
 		}
 	case 319:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4600
+		// line php5/php5.y:4645
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[3].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -7015,7 +7060,7 @@ yydefault:
 		}
 	case 320:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4617
+		// line php5/php5.y:4662
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[4].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -7037,7 +7082,7 @@ yydefault:
 		}
 	case 321:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4637
+		// line php5/php5.y:4682
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -7055,7 +7100,7 @@ yydefault:
 		}
 	case 322:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4653
+		// line php5/php5.y:4698
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[2].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -7076,7 +7121,7 @@ yydefault:
 		}
 	case 323:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4675
+		// line php5/php5.y:4720
 		{
 			name := name.NewName(yyDollar[1].list)
 			yyVAL.node = expr.NewFunctionCall(name, yyDollar[2].node.(*node.ArgumentList))
@@ -7092,7 +7137,7 @@ yydefault:
 		}
 	case 324:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4689
+		// line php5/php5.y:4734
 		{
 			funcName := name.NewRelative(yyDollar[3].list)
 			yyVAL.node = expr.NewFunctionCall(funcName, yyDollar[4].node.(*node.ArgumentList))
@@ -7109,7 +7154,7 @@ yydefault:
 		}
 	case 325:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4704
+		// line php5/php5.y:4749
 		{
 			funcName := name.NewFullyQualified(yyDollar[2].list)
 			yyVAL.node = expr.NewFunctionCall(funcName, yyDollar[3].node.(*node.ArgumentList))
@@ -7125,7 +7170,7 @@ yydefault:
 		}
 	case 326:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4718
+		// line php5/php5.y:4763
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -7140,7 +7185,7 @@ yydefault:
 		}
 	case 327:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4731
+		// line php5/php5.y:4776
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -7155,7 +7200,7 @@ yydefault:
 		}
 	case 328:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4744
+		// line php5/php5.y:4789
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -7170,7 +7215,7 @@ yydefault:
 		}
 	case 329:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4757
+		// line php5/php5.y:4802
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -7185,7 +7230,7 @@ yydefault:
 		}
 	case 330:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4770
+		// line php5/php5.y:4815
 		{
 			yyVAL.node = expr.NewFunctionCall(yyDollar[1].node, yyDollar[2].node.(*node.ArgumentList))
 
@@ -7199,7 +7244,7 @@ yydefault:
 		}
 	case 331:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4785
+		// line php5/php5.y:4830
 		{
 			yyVAL.node = node.NewIdentifier(yyDollar[1].token.Value)
 
@@ -7213,7 +7258,7 @@ yydefault:
 		}
 	case 332:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4797
+		// line php5/php5.y:4842
 		{
 			yyVAL.node = name.NewName(yyDollar[1].list)
 
@@ -7227,7 +7272,7 @@ yydefault:
 		}
 	case 333:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4809
+		// line php5/php5.y:4854
 		{
 			yyVAL.node = name.NewRelative(yyDollar[3].list)
 
@@ -7242,7 +7287,7 @@ yydefault:
 		}
 	case 334:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4822
+		// line php5/php5.y:4867
 		{
 			yyVAL.node = name.NewFullyQualified(yyDollar[2].list)
 
@@ -7256,7 +7301,7 @@ yydefault:
 		}
 	case 335:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4837
+		// line php5/php5.y:4882
 		{
 			yyVAL.node = name.NewName(yyDollar[1].list)
 
@@ -7270,7 +7315,7 @@ yydefault:
 		}
 	case 336:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:4849
+		// line php5/php5.y:4894
 		{
 			yyVAL.node = name.NewRelative(yyDollar[3].list)
 
@@ -7285,7 +7330,7 @@ yydefault:
 		}
 	case 337:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4862
+		// line php5/php5.y:4907
 		{
 			yyVAL.node = name.NewFullyQualified(yyDollar[2].list)
 
@@ -7299,7 +7344,7 @@ yydefault:
 		}
 	case 338:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4877
+		// line php5/php5.y:4922
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7307,7 +7352,7 @@ yydefault:
 		}
 	case 339:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4883
+		// line php5/php5.y:4928
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7315,7 +7360,7 @@ yydefault:
 		}
 	case 340:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:4892
+		// line php5/php5.y:4937
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7358,7 +7403,7 @@ yydefault:
 		}
 	case 341:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4933
+		// line php5/php5.y:4978
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7366,7 +7411,7 @@ yydefault:
 		}
 	case 342:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4943
+		// line php5/php5.y:4988
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[2].list...)
 
@@ -7374,7 +7419,7 @@ yydefault:
 		}
 	case 343:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:4949
+		// line php5/php5.y:4994
 		{
 			yyVAL.list = []node.Node{}
 
@@ -7382,7 +7427,7 @@ yydefault:
 		}
 	case 344:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4959
+		// line php5/php5.y:5004
 		{
 			yyVAL.list = yyDollar[2].list
 
@@ -7393,7 +7438,7 @@ yydefault:
 		}
 	case 345:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:4971
+		// line php5/php5.y:5016
 		{
 			yyVAL.node = expr.NewExit(nil)
 
@@ -7401,7 +7446,7 @@ yydefault:
 		}
 	case 346:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:4977
+		// line php5/php5.y:5022
 		{
 			yyVAL.node = expr.NewExit(nil)
 
@@ -7416,7 +7461,7 @@ yydefault:
 		}
 	case 347:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:4990
+		// line php5/php5.y:5035
 		{
 			yyVAL.node = expr.NewExit(yyDollar[1].node)
 
@@ -7437,7 +7482,7 @@ yydefault:
 		}
 	case 348:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:5010
+		// line php5/php5.y:5055
 		{
 			yyVAL.list = []node.Node{}
 
@@ -7445,7 +7490,7 @@ yydefault:
 		}
 	case 349:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5016
+		// line php5/php5.y:5061
 		{
 			part := scalar.NewEncapsedStringPart(yyDollar[1].token.Value)
 			yyVAL.list = []node.Node{part}
@@ -7457,7 +7502,7 @@ yydefault:
 		}
 	case 350:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5026
+		// line php5/php5.y:5071
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -7465,7 +7510,7 @@ yydefault:
 		}
 	case 351:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:5035
+		// line php5/php5.y:5080
 		{
 			yyVAL.node = nil
 
@@ -7473,7 +7518,7 @@ yydefault:
 		}
 	case 352:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5041
+		// line php5/php5.y:5086
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7481,7 +7526,7 @@ yydefault:
 		}
 	case 353:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5050
+		// line php5/php5.y:5095
 		{
 			yyVAL.node = scalar.NewLnumber(yyDollar[1].token.Value)
 
@@ -7495,7 +7540,7 @@ yydefault:
 		}
 	case 354:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5062
+		// line php5/php5.y:5107
 		{
 			yyVAL.node = scalar.NewDnumber(yyDollar[1].token.Value)
 
@@ -7509,7 +7554,7 @@ yydefault:
 		}
 	case 355:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5074
+		// line php5/php5.y:5119
 		{
 			yyVAL.node = scalar.NewString(yyDollar[1].token.Value)
 
@@ -7523,7 +7568,7 @@ yydefault:
 		}
 	case 356:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5086
+		// line php5/php5.y:5131
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7537,7 +7582,7 @@ yydefault:
 		}
 	case 357:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5098
+		// line php5/php5.y:5143
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7551,7 +7596,7 @@ yydefault:
 		}
 	case 358:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5110
+		// line php5/php5.y:5155
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7565,7 +7610,7 @@ yydefault:
 		}
 	case 359:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5122
+		// line php5/php5.y:5167
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7579,7 +7624,7 @@ yydefault:
 		}
 	case 360:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5134
+		// line php5/php5.y:5179
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7593,7 +7638,7 @@ yydefault:
 		}
 	case 361:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5146
+		// line php5/php5.y:5191
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7607,7 +7652,7 @@ yydefault:
 		}
 	case 362:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5158
+		// line php5/php5.y:5203
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7621,7 +7666,7 @@ yydefault:
 		}
 	case 363:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5170
+		// line php5/php5.y:5215
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[2].token.Value)
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, []node.Node{encapsed})
@@ -7637,7 +7682,7 @@ yydefault:
 		}
 	case 364:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5184
+		// line php5/php5.y:5229
 		{
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, nil)
 
@@ -7651,7 +7696,7 @@ yydefault:
 		}
 	case 365:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5199
+		// line php5/php5.y:5244
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -7669,7 +7714,7 @@ yydefault:
 		}
 	case 366:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5218
+		// line php5/php5.y:5263
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7677,7 +7722,7 @@ yydefault:
 		}
 	case 367:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5227
+		// line php5/php5.y:5272
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7685,7 +7730,7 @@ yydefault:
 		}
 	case 368:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5233
+		// line php5/php5.y:5278
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7693,7 +7738,7 @@ yydefault:
 		}
 	case 369:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5239
+		// line php5/php5.y:5284
 		{
 			name := name.NewName(yyDollar[1].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -7709,7 +7754,7 @@ yydefault:
 		}
 	case 370:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5253
+		// line php5/php5.y:5298
 		{
 			name := name.NewRelative(yyDollar[3].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -7726,7 +7771,7 @@ yydefault:
 		}
 	case 371:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5268
+		// line php5/php5.y:5313
 		{
 			name := name.NewFullyQualified(yyDollar[2].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -7742,7 +7787,7 @@ yydefault:
 		}
 	case 372:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:5282
+		// line php5/php5.y:5327
 		{
 			yyVAL.node = expr.NewArray(yyDollar[3].list)
 
@@ -7758,7 +7803,7 @@ yydefault:
 		}
 	case 373:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5296
+		// line php5/php5.y:5341
 		{
 			yyVAL.node = expr.NewShortArray(yyDollar[2].list)
 
@@ -7773,7 +7818,7 @@ yydefault:
 		}
 	case 374:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5309
+		// line php5/php5.y:5354
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7781,7 +7826,7 @@ yydefault:
 		}
 	case 375:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5315
+		// line php5/php5.y:5360
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7795,7 +7840,7 @@ yydefault:
 		}
 	case 376:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5327
+		// line php5/php5.y:5372
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7803,7 +7848,7 @@ yydefault:
 		}
 	case 377:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:5336
+		// line php5/php5.y:5381
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7819,7 +7864,7 @@ yydefault:
 		}
 	case 378:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5350
+		// line php5/php5.y:5395
 		{
 			yyVAL.node = binary.NewPlus(yyDollar[1].node, yyDollar[3].node)
 
@@ -7834,7 +7879,7 @@ yydefault:
 		}
 	case 379:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5363
+		// line php5/php5.y:5408
 		{
 			yyVAL.node = binary.NewMinus(yyDollar[1].node, yyDollar[3].node)
 
@@ -7849,7 +7894,7 @@ yydefault:
 		}
 	case 380:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5376
+		// line php5/php5.y:5421
 		{
 			yyVAL.node = binary.NewMul(yyDollar[1].node, yyDollar[3].node)
 
@@ -7864,7 +7909,7 @@ yydefault:
 		}
 	case 381:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5389
+		// line php5/php5.y:5434
 		{
 			yyVAL.node = binary.NewPow(yyDollar[1].node, yyDollar[3].node)
 
@@ -7879,7 +7924,7 @@ yydefault:
 		}
 	case 382:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5402
+		// line php5/php5.y:5447
 		{
 			yyVAL.node = binary.NewDiv(yyDollar[1].node, yyDollar[3].node)
 
@@ -7894,7 +7939,7 @@ yydefault:
 		}
 	case 383:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5415
+		// line php5/php5.y:5460
 		{
 			yyVAL.node = binary.NewMod(yyDollar[1].node, yyDollar[3].node)
 
@@ -7909,7 +7954,7 @@ yydefault:
 		}
 	case 384:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5428
+		// line php5/php5.y:5473
 		{
 			yyVAL.node = expr.NewBooleanNot(yyDollar[2].node)
 
@@ -7923,7 +7968,7 @@ yydefault:
 		}
 	case 385:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5440
+		// line php5/php5.y:5485
 		{
 			yyVAL.node = expr.NewBitwiseNot(yyDollar[2].node)
 
@@ -7937,7 +7982,7 @@ yydefault:
 		}
 	case 386:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5452
+		// line php5/php5.y:5497
 		{
 			yyVAL.node = binary.NewBitwiseOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -7952,7 +7997,7 @@ yydefault:
 		}
 	case 387:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5465
+		// line php5/php5.y:5510
 		{
 			yyVAL.node = binary.NewBitwiseAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -7967,7 +8012,7 @@ yydefault:
 		}
 	case 388:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5478
+		// line php5/php5.y:5523
 		{
 			yyVAL.node = binary.NewBitwiseXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -7982,7 +8027,7 @@ yydefault:
 		}
 	case 389:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5491
+		// line php5/php5.y:5536
 		{
 			yyVAL.node = binary.NewShiftLeft(yyDollar[1].node, yyDollar[3].node)
 
@@ -7997,7 +8042,7 @@ yydefault:
 		}
 	case 390:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5504
+		// line php5/php5.y:5549
 		{
 			yyVAL.node = binary.NewShiftRight(yyDollar[1].node, yyDollar[3].node)
 
@@ -8012,7 +8057,7 @@ yydefault:
 		}
 	case 391:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5517
+		// line php5/php5.y:5562
 		{
 			yyVAL.node = binary.NewConcat(yyDollar[1].node, yyDollar[3].node)
 
@@ -8027,7 +8072,7 @@ yydefault:
 		}
 	case 392:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5530
+		// line php5/php5.y:5575
 		{
 			yyVAL.node = binary.NewLogicalXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -8042,7 +8087,7 @@ yydefault:
 		}
 	case 393:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5543
+		// line php5/php5.y:5588
 		{
 			yyVAL.node = binary.NewLogicalAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -8057,7 +8102,7 @@ yydefault:
 		}
 	case 394:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5556
+		// line php5/php5.y:5601
 		{
 			yyVAL.node = binary.NewLogicalOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -8072,7 +8117,7 @@ yydefault:
 		}
 	case 395:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5569
+		// line php5/php5.y:5614
 		{
 			yyVAL.node = binary.NewBooleanAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -8087,7 +8132,7 @@ yydefault:
 		}
 	case 396:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5582
+		// line php5/php5.y:5627
 		{
 			yyVAL.node = binary.NewBooleanOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -8102,7 +8147,7 @@ yydefault:
 		}
 	case 397:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5595
+		// line php5/php5.y:5640
 		{
 			yyVAL.node = binary.NewIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -8117,7 +8162,7 @@ yydefault:
 		}
 	case 398:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5608
+		// line php5/php5.y:5653
 		{
 			yyVAL.node = binary.NewNotIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -8132,7 +8177,7 @@ yydefault:
 		}
 	case 399:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5621
+		// line php5/php5.y:5666
 		{
 			yyVAL.node = binary.NewEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -8147,7 +8192,7 @@ yydefault:
 		}
 	case 400:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5634
+		// line php5/php5.y:5679
 		{
 			yyVAL.node = binary.NewNotEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -8163,7 +8208,7 @@ yydefault:
 		}
 	case 401:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5648
+		// line php5/php5.y:5693
 		{
 			yyVAL.node = binary.NewSmaller(yyDollar[1].node, yyDollar[3].node)
 
@@ -8178,7 +8223,7 @@ yydefault:
 		}
 	case 402:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5661
+		// line php5/php5.y:5706
 		{
 			yyVAL.node = binary.NewGreater(yyDollar[1].node, yyDollar[3].node)
 
@@ -8193,7 +8238,7 @@ yydefault:
 		}
 	case 403:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5674
+		// line php5/php5.y:5719
 		{
 			yyVAL.node = binary.NewSmallerOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -8208,7 +8253,7 @@ yydefault:
 		}
 	case 404:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5687
+		// line php5/php5.y:5732
 		{
 			yyVAL.node = binary.NewGreaterOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -8223,7 +8268,7 @@ yydefault:
 		}
 	case 405:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:5700
+		// line php5/php5.y:5745
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, nil, yyDollar[4].node)
 
@@ -8239,7 +8284,7 @@ yydefault:
 		}
 	case 406:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php5/php5.y:5714
+		// line php5/php5.y:5759
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, yyDollar[3].node, yyDollar[5].node)
 
@@ -8255,7 +8300,7 @@ yydefault:
 		}
 	case 407:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5728
+		// line php5/php5.y:5773
 		{
 			yyVAL.node = expr.NewUnaryPlus(yyDollar[2].node)
 
@@ -8269,7 +8314,7 @@ yydefault:
 		}
 	case 408:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5740
+		// line php5/php5.y:5785
 		{
 			yyVAL.node = expr.NewUnaryMinus(yyDollar[2].node)
 
@@ -8283,7 +8328,7 @@ yydefault:
 		}
 	case 409:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5752
+		// line php5/php5.y:5797
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -8295,7 +8340,7 @@ yydefault:
 		}
 	case 410:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5765
+		// line php5/php5.y:5810
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8303,7 +8348,7 @@ yydefault:
 		}
 	case 411:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5771
+		// line php5/php5.y:5816
 		{
 			name := name.NewName(yyDollar[1].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -8319,7 +8364,7 @@ yydefault:
 		}
 	case 412:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5785
+		// line php5/php5.y:5830
 		{
 			name := name.NewRelative(yyDollar[3].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -8336,7 +8381,7 @@ yydefault:
 		}
 	case 413:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5800
+		// line php5/php5.y:5845
 		{
 			name := name.NewFullyQualified(yyDollar[2].list)
 			yyVAL.node = expr.NewConstFetch(name)
@@ -8352,7 +8397,7 @@ yydefault:
 		}
 	case 414:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5817
+		// line php5/php5.y:5862
 		{
 			name := node.NewIdentifier(yyDollar[1].token.Value)
 			yyVAL.node = expr.NewVariable(name)
@@ -8368,7 +8413,7 @@ yydefault:
 		}
 	case 415:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5831
+		// line php5/php5.y:5876
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8376,7 +8421,7 @@ yydefault:
 		}
 	case 416:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5837
+		// line php5/php5.y:5882
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8384,7 +8429,7 @@ yydefault:
 		}
 	case 417:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5843
+		// line php5/php5.y:5888
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8392,7 +8437,7 @@ yydefault:
 		}
 	case 418:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5849
+		// line php5/php5.y:5894
 		{
 			yyVAL.node = scalar.NewEncapsed(yyDollar[2].list)
 
@@ -8406,7 +8451,7 @@ yydefault:
 		}
 	case 419:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5861
+		// line php5/php5.y:5906
 		{
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, yyDollar[2].list)
 
@@ -8420,7 +8465,7 @@ yydefault:
 		}
 	case 420:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5873
+		// line php5/php5.y:5918
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -8434,7 +8479,7 @@ yydefault:
 		}
 	case 421:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:5888
+		// line php5/php5.y:5933
 		{
 			yyVAL.list = nil
 
@@ -8442,7 +8487,7 @@ yydefault:
 		}
 	case 422:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:5894
+		// line php5/php5.y:5939
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -8455,19 +8500,19 @@ yydefault:
 		}
 	case 423:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:5908
+		// line php5/php5.y:5953
 		{
 			yyVAL.token = nil
 		}
 	case 424:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5912
+		// line php5/php5.y:5957
 		{
 			yyVAL.token = yyDollar[1].token
 		}
 	case 425:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php5/php5.y:5919
+		// line php5/php5.y:5964
 		{
 			arrayItem := expr.NewArrayItem(yyDollar[3].node, yyDollar[5].node, false)
 			yyVAL.list = append(yyDollar[1].list, arrayItem)
@@ -8484,7 +8529,7 @@ yydefault:
 		}
 	case 426:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5934
+		// line php5/php5.y:5979
 		{
 			arrayItem := expr.NewArrayItem(nil, yyDollar[3].node, false)
 			yyVAL.list = append(yyDollar[1].list, arrayItem)
@@ -8500,7 +8545,7 @@ yydefault:
 		}
 	case 427:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5948
+		// line php5/php5.y:5993
 		{
 			arrayItem := expr.NewArrayItem(yyDollar[1].node, yyDollar[3].node, false)
 			yyVAL.list = []node.Node{arrayItem}
@@ -8516,7 +8561,7 @@ yydefault:
 		}
 	case 428:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5962
+		// line php5/php5.y:6007
 		{
 			arrayItem := expr.NewArrayItem(nil, yyDollar[1].node, false)
 			yyVAL.list = []node.Node{arrayItem}
@@ -8531,7 +8576,7 @@ yydefault:
 		}
 	case 429:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5978
+		// line php5/php5.y:6023
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8539,7 +8584,7 @@ yydefault:
 		}
 	case 430:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:5984
+		// line php5/php5.y:6029
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8547,7 +8592,7 @@ yydefault:
 		}
 	case 431:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:5993
+		// line php5/php5.y:6038
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -8565,7 +8610,7 @@ yydefault:
 		}
 	case 432:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6009
+		// line php5/php5.y:6054
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -8583,7 +8628,7 @@ yydefault:
 		}
 	case 433:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6029
+		// line php5/php5.y:6074
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8591,7 +8636,7 @@ yydefault:
 		}
 	case 434:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6039
+		// line php5/php5.y:6084
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8599,7 +8644,7 @@ yydefault:
 		}
 	case 435:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6048
+		// line php5/php5.y:6093
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8607,7 +8652,7 @@ yydefault:
 		}
 	case 436:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php5/php5.y:6057
+		// line php5/php5.y:6102
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8667,7 +8712,7 @@ yydefault:
 		}
 	case 437:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6115
+		// line php5/php5.y:6160
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8675,7 +8720,7 @@ yydefault:
 		}
 	case 438:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6124
+		// line php5/php5.y:6169
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[2].list...)
 
@@ -8683,7 +8728,7 @@ yydefault:
 		}
 	case 439:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:6130
+		// line php5/php5.y:6175
 		{
 			yyVAL.list = []node.Node{}
 
@@ -8691,7 +8736,7 @@ yydefault:
 		}
 	case 440:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6140
+		// line php5/php5.y:6185
 		{
 			if yyDollar[3].list != nil {
 				yyDollar[3].list[0].(*expr.MethodCall).Method = yyDollar[2].list[len(yyDollar[2].list)-1].(*expr.PropertyFetch).Property
@@ -8707,7 +8752,7 @@ yydefault:
 		}
 	case 441:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6157
+		// line php5/php5.y:6202
 		{
 			fetch := expr.NewArrayDimFetch(nil, yyDollar[3].node)
 			yyVAL.list = append(yyDollar[1].list, fetch)
@@ -8723,7 +8768,7 @@ yydefault:
 		}
 	case 442:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6171
+		// line php5/php5.y:6216
 		{
 			fetch := expr.NewArrayDimFetch(nil, yyDollar[3].node)
 			yyVAL.list = []node.Node{yyDollar[1].node, fetch}
@@ -8739,7 +8784,7 @@ yydefault:
 		}
 	case 443:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6188
+		// line php5/php5.y:6233
 		{
 			yyVAL.node = expr.NewMethodCall(nil, nil, yyDollar[1].node.(*node.ArgumentList))
 
@@ -8750,7 +8795,7 @@ yydefault:
 		}
 	case 444:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6200
+		// line php5/php5.y:6245
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -8758,7 +8803,7 @@ yydefault:
 		}
 	case 445:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6206
+		// line php5/php5.y:6251
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -8766,7 +8811,7 @@ yydefault:
 		}
 	case 446:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:6212
+		// line php5/php5.y:6257
 		{
 			yyVAL.list = nil
 
@@ -8774,7 +8819,7 @@ yydefault:
 		}
 	case 447:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6221
+		// line php5/php5.y:6266
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8782,7 +8827,7 @@ yydefault:
 		}
 	case 448:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6227
+		// line php5/php5.y:6272
 		{
 			yyDollar[1].simpleIndirectReference.last.SetVarName(yyDollar[2].node)
 
@@ -8796,7 +8841,7 @@ yydefault:
 		}
 	case 449:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6242
+		// line php5/php5.y:6287
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8811,7 +8856,7 @@ yydefault:
 		}
 	case 450:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6255
+		// line php5/php5.y:6300
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8826,7 +8871,7 @@ yydefault:
 		}
 	case 451:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6271
+		// line php5/php5.y:6316
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8834,7 +8879,7 @@ yydefault:
 		}
 	case 452:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6280
+		// line php5/php5.y:6325
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8850,7 +8895,7 @@ yydefault:
 		}
 	case 453:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6294
+		// line php5/php5.y:6339
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8866,7 +8911,7 @@ yydefault:
 		}
 	case 454:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6311
+		// line php5/php5.y:6356
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8874,7 +8919,7 @@ yydefault:
 		}
 	case 455:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6317
+		// line php5/php5.y:6362
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8882,7 +8927,7 @@ yydefault:
 		}
 	case 456:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6323
+		// line php5/php5.y:6368
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8890,7 +8935,7 @@ yydefault:
 		}
 	case 457:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6333
+		// line php5/php5.y:6378
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8898,7 +8943,7 @@ yydefault:
 		}
 	case 458:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6339
+		// line php5/php5.y:6384
 		{
 			yyDollar[1].simpleIndirectReference.last.SetVarName(yyDollar[2].node)
 
@@ -8912,7 +8957,7 @@ yydefault:
 		}
 	case 459:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6351
+		// line php5/php5.y:6396
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8920,7 +8965,7 @@ yydefault:
 		}
 	case 460:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6360
+		// line php5/php5.y:6405
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8936,7 +8981,7 @@ yydefault:
 		}
 	case 461:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6374
+		// line php5/php5.y:6419
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -8952,7 +8997,7 @@ yydefault:
 		}
 	case 462:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6388
+		// line php5/php5.y:6433
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -8960,7 +9005,7 @@ yydefault:
 		}
 	case 463:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6398
+		// line php5/php5.y:6443
 		{
 			name := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(name)
@@ -8977,7 +9022,7 @@ yydefault:
 		}
 	case 464:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6413
+		// line php5/php5.y:6458
 		{
 			yyVAL.node = expr.NewVariable(yyDollar[3].node)
 
@@ -8994,7 +9039,7 @@ yydefault:
 		}
 	case 465:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:6431
+		// line php5/php5.y:6476
 		{
 			yyVAL.node = nil
 
@@ -9002,7 +9047,7 @@ yydefault:
 		}
 	case 466:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6437
+		// line php5/php5.y:6482
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -9010,7 +9055,7 @@ yydefault:
 		}
 	case 467:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6447
+		// line php5/php5.y:6492
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -9018,7 +9063,7 @@ yydefault:
 		}
 	case 468:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6453
+		// line php5/php5.y:6498
 		{
 			fetch := expr.NewPropertyFetch(nil, yyDollar[1].node)
 			yyVAL.list = []node.Node{fetch}
@@ -9030,7 +9075,7 @@ yydefault:
 		}
 	case 469:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6466
+		// line php5/php5.y:6511
 		{
 			fetch := expr.NewArrayDimFetch(nil, yyDollar[3].node)
 			yyVAL.list = append(yyDollar[1].list, fetch)
@@ -9046,7 +9091,7 @@ yydefault:
 		}
 	case 470:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6480
+		// line php5/php5.y:6525
 		{
 			fetch := expr.NewArrayDimFetch(nil, yyDollar[3].node)
 			yyVAL.list = append(yyDollar[1].list, fetch)
@@ -9062,7 +9107,7 @@ yydefault:
 		}
 	case 471:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6494
+		// line php5/php5.y:6539
 		{
 			fetch := expr.NewPropertyFetch(nil, yyDollar[1].node)
 			yyVAL.list = []node.Node{fetch}
@@ -9074,7 +9119,7 @@ yydefault:
 		}
 	case 472:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6507
+		// line php5/php5.y:6552
 		{
 			yyVAL.node = node.NewIdentifier(yyDollar[1].token.Value)
 
@@ -9088,7 +9133,7 @@ yydefault:
 		}
 	case 473:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6519
+		// line php5/php5.y:6564
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -9103,7 +9148,7 @@ yydefault:
 		}
 	case 474:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6535
+		// line php5/php5.y:6580
 		{
 			n := expr.NewVariable(nil)
 			yyVAL.simpleIndirectReference = simpleIndirectReference{[]*expr.Variable{n}, n}
@@ -9119,7 +9164,7 @@ yydefault:
 		}
 	case 475:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6549
+		// line php5/php5.y:6594
 		{
 			n := expr.NewVariable(nil)
 
@@ -9139,7 +9184,7 @@ yydefault:
 		}
 	case 476:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6570
+		// line php5/php5.y:6615
 		{
 			if len(yyDollar[1].list) == 0 {
 				yyDollar[1].list = []node.Node{expr.NewArrayItem(nil, nil, false)}
@@ -9154,7 +9199,7 @@ yydefault:
 		}
 	case 477:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6583
+		// line php5/php5.y:6628
 		{
 			if yyDollar[1].node.(*expr.ArrayItem).Key == nil && yyDollar[1].node.(*expr.ArrayItem).Val == nil {
 				yyVAL.list = []node.Node{}
@@ -9166,7 +9211,7 @@ yydefault:
 		}
 	case 478:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6597
+		// line php5/php5.y:6642
 		{
 			yyVAL.node = expr.NewArrayItem(nil, yyDollar[1].node, false)
 
@@ -9180,7 +9225,7 @@ yydefault:
 		}
 	case 479:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6609
+		// line php5/php5.y:6654
 		{
 			listNode := expr.NewList(yyDollar[3].list)
 			yyVAL.node = expr.NewArrayItem(nil, listNode, false)
@@ -9198,7 +9243,7 @@ yydefault:
 		}
 	case 480:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:6625
+		// line php5/php5.y:6670
 		{
 			yyVAL.node = expr.NewArrayItem(nil, nil, false)
 
@@ -9206,7 +9251,7 @@ yydefault:
 		}
 	case 481:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php5/php5.y:6635
+		// line php5/php5.y:6680
 		{
 			yyVAL.list = []node.Node{}
 
@@ -9214,7 +9259,7 @@ yydefault:
 		}
 	case 482:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6641
+		// line php5/php5.y:6686
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -9231,7 +9276,7 @@ yydefault:
 		}
 	case 483:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php5/php5.y:6659
+		// line php5/php5.y:6704
 		{
 			arrayItem := expr.NewArrayItem(yyDollar[3].node, yyDollar[5].node, false)
 			yyVAL.list = append(yyDollar[1].list, arrayItem)
@@ -9248,7 +9293,7 @@ yydefault:
 		}
 	case 484:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6674
+		// line php5/php5.y:6719
 		{
 			arrayItem := expr.NewArrayItem(nil, yyDollar[3].node, false)
 			yyVAL.list = append(yyDollar[1].list, arrayItem)
@@ -9264,7 +9309,7 @@ yydefault:
 		}
 	case 485:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6688
+		// line php5/php5.y:6733
 		{
 			arrayItem := expr.NewArrayItem(yyDollar[1].node, yyDollar[3].node, false)
 			yyVAL.list = []node.Node{arrayItem}
@@ -9280,7 +9325,7 @@ yydefault:
 		}
 	case 486:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6702
+		// line php5/php5.y:6747
 		{
 			arrayItem := expr.NewArrayItem(nil, yyDollar[1].node, false)
 			yyVAL.list = []node.Node{arrayItem}
@@ -9295,7 +9340,7 @@ yydefault:
 		}
 	case 487:
 		yyDollar = yyS[yypt-6 : yypt+1]
-		// line php5/php5.y:6715
+		// line php5/php5.y:6760
 		{
 			reference := expr.NewReference(yyDollar[6].node)
 			arrayItem := expr.NewArrayItem(yyDollar[3].node, reference, false)
@@ -9315,7 +9360,7 @@ yydefault:
 		}
 	case 488:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6733
+		// line php5/php5.y:6778
 		{
 			reference := expr.NewReference(yyDollar[4].node)
 			arrayItem := expr.NewArrayItem(nil, reference, false)
@@ -9333,7 +9378,7 @@ yydefault:
 		}
 	case 489:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6749
+		// line php5/php5.y:6794
 		{
 			reference := expr.NewReference(yyDollar[4].node)
 			arrayItem := expr.NewArrayItem(yyDollar[1].node, reference, false)
@@ -9352,7 +9397,7 @@ yydefault:
 		}
 	case 490:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6766
+		// line php5/php5.y:6811
 		{
 			reference := expr.NewReference(yyDollar[2].node)
 			arrayItem := expr.NewArrayItem(nil, reference, false)
@@ -9369,7 +9414,7 @@ yydefault:
 		}
 	case 491:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6784
+		// line php5/php5.y:6829
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[2].node)
 
@@ -9377,7 +9422,7 @@ yydefault:
 		}
 	case 492:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6790
+		// line php5/php5.y:6835
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[2].token.Value)
 			yyVAL.list = append(yyDollar[1].list, encapsed)
@@ -9392,7 +9437,7 @@ yydefault:
 		}
 	case 493:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6803
+		// line php5/php5.y:6848
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -9400,7 +9445,7 @@ yydefault:
 		}
 	case 494:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:6809
+		// line php5/php5.y:6854
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[1].token.Value)
 			yyVAL.list = []node.Node{encapsed, yyDollar[2].node}
@@ -9415,7 +9460,7 @@ yydefault:
 		}
 	case 495:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6825
+		// line php5/php5.y:6870
 		{
 			name := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(name)
@@ -9432,7 +9477,7 @@ yydefault:
 		}
 	case 496:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6840
+		// line php5/php5.y:6885
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -9452,7 +9497,7 @@ yydefault:
 		}
 	case 497:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6858
+		// line php5/php5.y:6903
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -9474,7 +9519,7 @@ yydefault:
 		}
 	case 498:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6878
+		// line php5/php5.y:6923
 		{
 			variable := expr.NewVariable(yyDollar[2].node)
 
@@ -9491,7 +9536,7 @@ yydefault:
 		}
 	case 499:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6893
+		// line php5/php5.y:6938
 		{
 			name := node.NewIdentifier(yyDollar[2].token.Value)
 			variable := expr.NewVariable(name)
@@ -9510,7 +9555,7 @@ yydefault:
 		}
 	case 500:
 		yyDollar = yyS[yypt-6 : yypt+1]
-		// line php5/php5.y:6910
+		// line php5/php5.y:6955
 		{
 			identifier := node.NewIdentifier(yyDollar[2].token.Value)
 			variable := expr.NewVariable(identifier)
@@ -9531,7 +9576,7 @@ yydefault:
 		}
 	case 501:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:6929
+		// line php5/php5.y:6974
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -9543,7 +9588,7 @@ yydefault:
 		}
 	case 502:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6942
+		// line php5/php5.y:6987
 		{
 			yyVAL.node = scalar.NewString(yyDollar[1].token.Value)
 
@@ -9557,7 +9602,7 @@ yydefault:
 		}
 	case 503:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6954
+		// line php5/php5.y:6999
 		{
 			// TODO: add option to handle 64 bit integer
 			if _, err := strconv.Atoi(yyDollar[1].token.Value); err == nil {
@@ -9576,7 +9621,7 @@ yydefault:
 		}
 	case 504:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:6971
+		// line php5/php5.y:7016
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(identifier)
@@ -9593,7 +9638,7 @@ yydefault:
 		}
 	case 505:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:6989
+		// line php5/php5.y:7034
 		{
 			yyVAL.node = expr.NewIsset(yyDollar[3].list)
 
@@ -9609,7 +9654,7 @@ yydefault:
 		}
 	case 506:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:7003
+		// line php5/php5.y:7048
 		{
 			yyVAL.node = expr.NewEmpty(yyDollar[3].node)
 
@@ -9625,7 +9670,7 @@ yydefault:
 		}
 	case 507:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:7017
+		// line php5/php5.y:7062
 		{
 			yyVAL.node = expr.NewEmpty(yyDollar[3].node)
 
@@ -9641,7 +9686,7 @@ yydefault:
 		}
 	case 508:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:7031
+		// line php5/php5.y:7076
 		{
 			yyVAL.node = expr.NewInclude(yyDollar[2].node)
 
@@ -9655,7 +9700,7 @@ yydefault:
 		}
 	case 509:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:7043
+		// line php5/php5.y:7088
 		{
 			yyVAL.node = expr.NewIncludeOnce(yyDollar[2].node)
 
@@ -9669,7 +9714,7 @@ yydefault:
 		}
 	case 510:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php5/php5.y:7055
+		// line php5/php5.y:7100
 		{
 			yyVAL.node = expr.NewEval(yyDollar[3].node)
 
@@ -9685,7 +9730,7 @@ yydefault:
 		}
 	case 511:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:7069
+		// line php5/php5.y:7114
 		{
 			yyVAL.node = expr.NewRequire(yyDollar[2].node)
 
@@ -9699,7 +9744,7 @@ yydefault:
 		}
 	case 512:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php5/php5.y:7081
+		// line php5/php5.y:7126
 		{
 			yyVAL.node = expr.NewRequireOnce(yyDollar[2].node)
 
@@ -9713,7 +9758,7 @@ yydefault:
 		}
 	case 513:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:7096
+		// line php5/php5.y:7141
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -9721,7 +9766,7 @@ yydefault:
 		}
 	case 514:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:7102
+		// line php5/php5.y:7147
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[3].node)
 
@@ -9732,7 +9777,7 @@ yydefault:
 		}
 	case 515:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:7114
+		// line php5/php5.y:7159
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -9740,7 +9785,7 @@ yydefault:
 		}
 	case 516:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php5/php5.y:7120
+		// line php5/php5.y:7165
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -9748,7 +9793,7 @@ yydefault:
 		}
 	case 517:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:7129
+		// line php5/php5.y:7174
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -9766,7 +9811,7 @@ yydefault:
 		}
 	case 518:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:7145
+		// line php5/php5.y:7190
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -9784,7 +9829,7 @@ yydefault:
 		}
 	case 519:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:7164
+		// line php5/php5.y:7209
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -9802,7 +9847,7 @@ yydefault:
 		}
 	case 520:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php5/php5.y:7183
+		// line php5/php5.y:7228
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -3460,6 +3460,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable '=' '&' variable
@@ -3474,6 +3477,9 @@ expr_without_variable:
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Equal, $3.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable '=' '&' T_NEW class_name_reference ctor_arguments
@@ -3501,6 +3507,9 @@ expr_without_variable:
                 yylex.(*Parser).setFreeFloating($$, freefloating.Equal, $3.FreeFloating)
                 yylex.(*Parser).setFreeFloating(_new, freefloating.Start, $4.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   T_CLONE expr
@@ -3525,6 +3534,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MINUS_EQUAL expr
@@ -3538,6 +3550,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MUL_EQUAL expr
@@ -3551,6 +3566,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_POW_EQUAL expr
@@ -3564,6 +3582,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_DIV_EQUAL expr
@@ -3577,6 +3598,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_CONCAT_EQUAL expr
@@ -3590,6 +3614,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MOD_EQUAL expr
@@ -3603,6 +3630,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_AND_EQUAL expr
@@ -3616,6 +3646,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_OR_EQUAL expr
@@ -3629,6 +3662,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_XOR_EQUAL expr
@@ -3642,6 +3678,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_SL_EQUAL expr
@@ -3655,6 +3694,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_SR_EQUAL expr
@@ -3668,6 +3710,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   rw_variable T_INC
@@ -18731,3 +18731,27 @@ func TestPhp5ControlCharsErrors(t *testing.T) {
 	actual := php5parser.GetErrors()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestPhp5AssignToCallErrors(t *testing.T) {
+	src := `<?php foo() = 1; $a->b() = 1; A::c() += 1; $a = 1;`
+
+	expected := []*errors.Error{
+		{
+			Msg: "Can't use function return value in write context",
+			Pos: &position.Position{1, 1, 6, 11},
+		},
+		{
+			Msg: "Can't use method return value in write context",
+			Pos: &position.Position{1, 1, 17, 24},
+		},
+		{
+			Msg: "Can't use method return value in write context",
+			Pos: &position.Position{1, 1, 30, 36},
+		},
+	}
+
+	php5parser := php5.NewParser([]byte(src), "5.6")
+	php5parser.Parse()
+	actual := php5parser.GetErrors()
+	assert.DeepEqual(t, expected, actual)
+}
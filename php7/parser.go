@@ -6,6 +6,7 @@ import (
 	"github.com/z7zmey/php-parser/errors"
 	"github.com/z7zmey/php-parser/freefloating"
 	"github.com/z7zmey/php-parser/node"
+	"github.com/z7zmey/php-parser/node/expr"
 	"github.com/z7zmey/php-parser/position"
 	"github.com/z7zmey/php-parser/positionbuilder"
 	"github.com/z7zmey/php-parser/scanner"
@@ -153,6 +154,16 @@ func (l *Parser) addDollarToken(v node.Node) {
 	})
 }
 
+// checkAssignTarget reports an error when a call is used as an assignment target
+func (l *Parser) checkAssignTarget(n node.Node) {
+	switch n.(type) {
+	case *expr.FunctionCall:
+		l.Lexer.AddError(errors.NewError("Can't use function return value in write context", n.GetPosition()))
+	case *expr.MethodCall, *expr.StaticCall:
+		l.Lexer.AddError(errors.NewError("Can't use method return value in write context", n.GetPosition()))
+	}
+}
+
 func (l *Parser) splitSemiColonAndPhpCloseTag(htmlNode node.Node, prevNode node.Node) {
 	if l.Lexer.GetWithFreeFloating() == false {
 		return
@@ -350,7 +350,7 @@ const yyEofCode = 1
 const yyErrCode = 2
 const yyInitialStackSize = 16
 
-// line php7/php7.y:5666
+// line php7/php7.y:5711
 
 // line yacctab:1
 var yyExca = [...]int{
@@ -5656,11 +5656,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 299:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:3223
+		// line php7/php7.y:3226
 		{
 			yyVAL.node = assign.NewReference(yyDollar[1].node, yyDollar[4].node)
 
@@ -5672,11 +5675,14 @@ yydefault:
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Equal, yyDollar[3].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 300:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3237
+		// line php7/php7.y:3243
 		{
 			yyVAL.node = expr.NewClone(yyDollar[2].node)
 
@@ -5690,7 +5696,7 @@ yydefault:
 		}
 	case 301:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3249
+		// line php7/php7.y:3255
 		{
 			yyVAL.node = assign.NewPlus(yyDollar[1].node, yyDollar[3].node)
 
@@ -5701,11 +5707,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 302:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3262
+		// line php7/php7.y:3271
 		{
 			yyVAL.node = assign.NewMinus(yyDollar[1].node, yyDollar[3].node)
 
@@ -5716,11 +5725,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 303:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3275
+		// line php7/php7.y:3287
 		{
 			yyVAL.node = assign.NewMul(yyDollar[1].node, yyDollar[3].node)
 
@@ -5731,11 +5743,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 304:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3288
+		// line php7/php7.y:3303
 		{
 			yyVAL.node = assign.NewPow(yyDollar[1].node, yyDollar[3].node)
 
@@ -5746,11 +5761,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 305:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3301
+		// line php7/php7.y:3319
 		{
 			yyVAL.node = assign.NewDiv(yyDollar[1].node, yyDollar[3].node)
 
@@ -5761,11 +5779,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 306:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3314
+		// line php7/php7.y:3335
 		{
 			yyVAL.node = assign.NewConcat(yyDollar[1].node, yyDollar[3].node)
 
@@ -5776,11 +5797,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 307:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3327
+		// line php7/php7.y:3351
 		{
 			yyVAL.node = assign.NewMod(yyDollar[1].node, yyDollar[3].node)
 
@@ -5791,11 +5815,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 308:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3340
+		// line php7/php7.y:3367
 		{
 			yyVAL.node = assign.NewBitwiseAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -5806,11 +5833,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 309:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3353
+		// line php7/php7.y:3383
 		{
 			yyVAL.node = assign.NewBitwiseOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -5821,11 +5851,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 310:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3366
+		// line php7/php7.y:3399
 		{
 			yyVAL.node = assign.NewBitwiseXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -5836,11 +5869,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 311:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3379
+		// line php7/php7.y:3415
 		{
 			yyVAL.node = assign.NewShiftLeft(yyDollar[1].node, yyDollar[3].node)
 
@@ -5851,11 +5887,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 312:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3392
+		// line php7/php7.y:3431
 		{
 			yyVAL.node = assign.NewShiftRight(yyDollar[1].node, yyDollar[3].node)
 
@@ -5866,11 +5905,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 313:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3405
+		// line php7/php7.y:3447
 		{
 			yyVAL.node = assign.NewCoalesce(yyDollar[1].node, yyDollar[3].node)
 
@@ -5881,11 +5923,14 @@ yydefault:
 			yylex.(*Parser).MoveFreeFloating(yyDollar[1].node, yyVAL.node)
 			yylex.(*Parser).setFreeFloating(yyVAL.node, freefloating.Var, yyDollar[2].token.FreeFloating)
 
+			// check assign target
+			yylex.(*Parser).checkAssignTarget(yyDollar[1].node)
+
 			yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
 		}
 	case 314:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3418
+		// line php7/php7.y:3463
 		{
 			yyVAL.node = expr.NewPostInc(yyDollar[1].node)
 
@@ -5900,7 +5945,7 @@ yydefault:
 		}
 	case 315:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3431
+		// line php7/php7.y:3476
 		{
 			yyVAL.node = expr.NewPreInc(yyDollar[2].node)
 
@@ -5914,7 +5959,7 @@ yydefault:
 		}
 	case 316:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3443
+		// line php7/php7.y:3488
 		{
 			yyVAL.node = expr.NewPostDec(yyDollar[1].node)
 
@@ -5929,7 +5974,7 @@ yydefault:
 		}
 	case 317:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3456
+		// line php7/php7.y:3501
 		{
 			yyVAL.node = expr.NewPreDec(yyDollar[2].node)
 
@@ -5943,7 +5988,7 @@ yydefault:
 		}
 	case 318:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3468
+		// line php7/php7.y:3513
 		{
 			yyVAL.node = binary.NewBooleanOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -5958,7 +6003,7 @@ yydefault:
 		}
 	case 319:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3481
+		// line php7/php7.y:3526
 		{
 			yyVAL.node = binary.NewBooleanAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -5973,7 +6018,7 @@ yydefault:
 		}
 	case 320:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3494
+		// line php7/php7.y:3539
 		{
 			yyVAL.node = binary.NewLogicalOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -5988,7 +6033,7 @@ yydefault:
 		}
 	case 321:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3507
+		// line php7/php7.y:3552
 		{
 			yyVAL.node = binary.NewLogicalAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -6003,7 +6048,7 @@ yydefault:
 		}
 	case 322:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3520
+		// line php7/php7.y:3565
 		{
 			yyVAL.node = binary.NewLogicalXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -6018,7 +6063,7 @@ yydefault:
 		}
 	case 323:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3533
+		// line php7/php7.y:3578
 		{
 			yyVAL.node = binary.NewBitwiseOr(yyDollar[1].node, yyDollar[3].node)
 
@@ -6033,7 +6078,7 @@ yydefault:
 		}
 	case 324:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3546
+		// line php7/php7.y:3591
 		{
 			yyVAL.node = binary.NewBitwiseAnd(yyDollar[1].node, yyDollar[3].node)
 
@@ -6048,7 +6093,7 @@ yydefault:
 		}
 	case 325:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3559
+		// line php7/php7.y:3604
 		{
 			yyVAL.node = binary.NewBitwiseXor(yyDollar[1].node, yyDollar[3].node)
 
@@ -6063,7 +6108,7 @@ yydefault:
 		}
 	case 326:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3572
+		// line php7/php7.y:3617
 		{
 			yyVAL.node = binary.NewConcat(yyDollar[1].node, yyDollar[3].node)
 
@@ -6078,7 +6123,7 @@ yydefault:
 		}
 	case 327:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3585
+		// line php7/php7.y:3630
 		{
 			yyVAL.node = binary.NewPlus(yyDollar[1].node, yyDollar[3].node)
 
@@ -6093,7 +6138,7 @@ yydefault:
 		}
 	case 328:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3598
+		// line php7/php7.y:3643
 		{
 			yyVAL.node = binary.NewMinus(yyDollar[1].node, yyDollar[3].node)
 
@@ -6108,7 +6153,7 @@ yydefault:
 		}
 	case 329:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3611
+		// line php7/php7.y:3656
 		{
 			yyVAL.node = binary.NewMul(yyDollar[1].node, yyDollar[3].node)
 
@@ -6123,7 +6168,7 @@ yydefault:
 		}
 	case 330:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3624
+		// line php7/php7.y:3669
 		{
 			yyVAL.node = binary.NewPow(yyDollar[1].node, yyDollar[3].node)
 
@@ -6138,7 +6183,7 @@ yydefault:
 		}
 	case 331:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3637
+		// line php7/php7.y:3682
 		{
 			yyVAL.node = binary.NewDiv(yyDollar[1].node, yyDollar[3].node)
 
@@ -6153,7 +6198,7 @@ yydefault:
 		}
 	case 332:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3650
+		// line php7/php7.y:3695
 		{
 			yyVAL.node = binary.NewMod(yyDollar[1].node, yyDollar[3].node)
 
@@ -6168,7 +6213,7 @@ yydefault:
 		}
 	case 333:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3663
+		// line php7/php7.y:3708
 		{
 			yyVAL.node = binary.NewShiftLeft(yyDollar[1].node, yyDollar[3].node)
 
@@ -6183,7 +6228,7 @@ yydefault:
 		}
 	case 334:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3676
+		// line php7/php7.y:3721
 		{
 			yyVAL.node = binary.NewShiftRight(yyDollar[1].node, yyDollar[3].node)
 
@@ -6198,7 +6243,7 @@ yydefault:
 		}
 	case 335:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3689
+		// line php7/php7.y:3734
 		{
 			yyVAL.node = expr.NewUnaryPlus(yyDollar[2].node)
 
@@ -6212,7 +6257,7 @@ yydefault:
 		}
 	case 336:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3701
+		// line php7/php7.y:3746
 		{
 			yyVAL.node = expr.NewUnaryMinus(yyDollar[2].node)
 
@@ -6226,7 +6271,7 @@ yydefault:
 		}
 	case 337:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3713
+		// line php7/php7.y:3758
 		{
 			yyVAL.node = expr.NewBooleanNot(yyDollar[2].node)
 
@@ -6240,7 +6285,7 @@ yydefault:
 		}
 	case 338:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3725
+		// line php7/php7.y:3770
 		{
 			yyVAL.node = expr.NewBitwiseNot(yyDollar[2].node)
 
@@ -6254,7 +6299,7 @@ yydefault:
 		}
 	case 339:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3737
+		// line php7/php7.y:3782
 		{
 			yyVAL.node = binary.NewIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -6269,7 +6314,7 @@ yydefault:
 		}
 	case 340:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3750
+		// line php7/php7.y:3795
 		{
 			yyVAL.node = binary.NewNotIdentical(yyDollar[1].node, yyDollar[3].node)
 
@@ -6284,7 +6329,7 @@ yydefault:
 		}
 	case 341:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3763
+		// line php7/php7.y:3808
 		{
 			yyVAL.node = binary.NewEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6299,7 +6344,7 @@ yydefault:
 		}
 	case 342:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3776
+		// line php7/php7.y:3821
 		{
 			yyVAL.node = binary.NewNotEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6315,7 +6360,7 @@ yydefault:
 		}
 	case 343:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3790
+		// line php7/php7.y:3835
 		{
 			yyVAL.node = binary.NewSmaller(yyDollar[1].node, yyDollar[3].node)
 
@@ -6330,7 +6375,7 @@ yydefault:
 		}
 	case 344:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3803
+		// line php7/php7.y:3848
 		{
 			yyVAL.node = binary.NewSmallerOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6345,7 +6390,7 @@ yydefault:
 		}
 	case 345:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3816
+		// line php7/php7.y:3861
 		{
 			yyVAL.node = binary.NewGreater(yyDollar[1].node, yyDollar[3].node)
 
@@ -6360,7 +6405,7 @@ yydefault:
 		}
 	case 346:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3829
+		// line php7/php7.y:3874
 		{
 			yyVAL.node = binary.NewGreaterOrEqual(yyDollar[1].node, yyDollar[3].node)
 
@@ -6375,7 +6420,7 @@ yydefault:
 		}
 	case 347:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3842
+		// line php7/php7.y:3887
 		{
 			yyVAL.node = binary.NewSpaceship(yyDollar[1].node, yyDollar[3].node)
 
@@ -6390,7 +6435,7 @@ yydefault:
 		}
 	case 348:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3855
+		// line php7/php7.y:3900
 		{
 			yyVAL.node = expr.NewInstanceOf(yyDollar[1].node, yyDollar[3].node)
 
@@ -6405,7 +6450,7 @@ yydefault:
 		}
 	case 349:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3868
+		// line php7/php7.y:3913
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -6417,7 +6462,7 @@ yydefault:
 		}
 	case 350:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:3878
+		// line php7/php7.y:3923
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6425,7 +6470,7 @@ yydefault:
 		}
 	case 351:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php7/php7.y:3884
+		// line php7/php7.y:3929
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, yyDollar[3].node, yyDollar[5].node)
 
@@ -6441,7 +6486,7 @@ yydefault:
 		}
 	case 352:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:3898
+		// line php7/php7.y:3943
 		{
 			yyVAL.node = expr.NewTernary(yyDollar[1].node, nil, yyDollar[4].node)
 
@@ -6457,7 +6502,7 @@ yydefault:
 		}
 	case 353:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:3912
+		// line php7/php7.y:3957
 		{
 			yyVAL.node = binary.NewCoalesce(yyDollar[1].node, yyDollar[3].node)
 
@@ -6472,7 +6517,7 @@ yydefault:
 		}
 	case 354:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:3925
+		// line php7/php7.y:3970
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6480,7 +6525,7 @@ yydefault:
 		}
 	case 355:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3931
+		// line php7/php7.y:3976
 		{
 			yyVAL.node = cast.NewInt(yyDollar[2].node)
 
@@ -6495,7 +6540,7 @@ yydefault:
 		}
 	case 356:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3944
+		// line php7/php7.y:3989
 		{
 			yyVAL.node = cast.NewDouble(yyDollar[2].node)
 
@@ -6510,7 +6555,7 @@ yydefault:
 		}
 	case 357:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3957
+		// line php7/php7.y:4002
 		{
 			yyVAL.node = cast.NewString(yyDollar[2].node)
 
@@ -6525,7 +6570,7 @@ yydefault:
 		}
 	case 358:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3970
+		// line php7/php7.y:4015
 		{
 			yyVAL.node = cast.NewArray(yyDollar[2].node)
 
@@ -6540,7 +6585,7 @@ yydefault:
 		}
 	case 359:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3983
+		// line php7/php7.y:4028
 		{
 			yyVAL.node = cast.NewObject(yyDollar[2].node)
 
@@ -6555,7 +6600,7 @@ yydefault:
 		}
 	case 360:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:3996
+		// line php7/php7.y:4041
 		{
 			yyVAL.node = cast.NewBool(yyDollar[2].node)
 
@@ -6570,7 +6615,7 @@ yydefault:
 		}
 	case 361:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4009
+		// line php7/php7.y:4054
 		{
 			yyVAL.node = cast.NewUnset(yyDollar[2].node)
 
@@ -6585,7 +6630,7 @@ yydefault:
 		}
 	case 362:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4022
+		// line php7/php7.y:4067
 		{
 			var e *expr.Exit
 			if yyDollar[2].node != nil {
@@ -6614,7 +6659,7 @@ yydefault:
 		}
 	case 363:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4049
+		// line php7/php7.y:4094
 		{
 			yyVAL.node = expr.NewErrorSuppress(yyDollar[2].node)
 
@@ -6628,7 +6673,7 @@ yydefault:
 		}
 	case 364:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4061
+		// line php7/php7.y:4106
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6636,7 +6681,7 @@ yydefault:
 		}
 	case 365:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4067
+		// line php7/php7.y:4112
 		{
 			yyVAL.node = expr.NewShellExec(yyDollar[2].list)
 
@@ -6650,7 +6695,7 @@ yydefault:
 		}
 	case 366:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4079
+		// line php7/php7.y:4124
 		{
 			yyVAL.node = expr.NewPrint(yyDollar[2].node)
 
@@ -6664,7 +6709,7 @@ yydefault:
 		}
 	case 367:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4091
+		// line php7/php7.y:4136
 		{
 			yyVAL.node = expr.NewYield(nil, nil)
 
@@ -6678,7 +6723,7 @@ yydefault:
 		}
 	case 368:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4103
+		// line php7/php7.y:4148
 		{
 			yyVAL.node = expr.NewYield(nil, yyDollar[2].node)
 
@@ -6692,7 +6737,7 @@ yydefault:
 		}
 	case 369:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4115
+		// line php7/php7.y:4160
 		{
 			yyVAL.node = expr.NewYield(yyDollar[2].node, yyDollar[4].node)
 
@@ -6707,7 +6752,7 @@ yydefault:
 		}
 	case 370:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4128
+		// line php7/php7.y:4173
 		{
 			yyVAL.node = expr.NewYieldFrom(yyDollar[2].node)
 
@@ -6721,7 +6766,7 @@ yydefault:
 		}
 	case 371:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4140
+		// line php7/php7.y:4185
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -6729,7 +6774,7 @@ yydefault:
 		}
 	case 372:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4146
+		// line php7/php7.y:4191
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -6752,7 +6797,7 @@ yydefault:
 		}
 	case 373:
 		yyDollar = yyS[yypt-11 : yypt+1]
-		// line php7/php7.y:4169
+		// line php7/php7.y:4214
 		{
 			yyVAL.node = expr.NewClosure(yyDollar[5].list, yyDollar[7].ClosureUse, yyDollar[8].node, yyDollar[10].list, false, yyDollar[2].token != nil, yyDollar[3].str)
 
@@ -6789,7 +6834,7 @@ yydefault:
 		}
 	case 374:
 		yyDollar = yyS[yypt-9 : yypt+1]
-		// line php7/php7.y:4201
+		// line php7/php7.y:4246
 		{
 			yyVAL.node = expr.NewArrowFunction(yyDollar[4].list, yyDollar[6].node, yyDollar[9].node, false, yyDollar[2].token != nil, yyDollar[7].str)
 
@@ -6821,7 +6866,7 @@ yydefault:
 		}
 	case 375:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4232
+		// line php7/php7.y:4277
 		{
 			yyVAL.str = yylex.(*Parser).Lexer.GetPhpDocComment()
 			yylex.(*Parser).Lexer.SetPhpDocComment("")
@@ -6830,19 +6875,19 @@ yydefault:
 		}
 	case 376:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4242
+		// line php7/php7.y:4287
 		{
 			yyVAL.token = nil
 		}
 	case 377:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4246
+		// line php7/php7.y:4291
 		{
 			yyVAL.token = yyDollar[1].token
 		}
 	case 378:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4253
+		// line php7/php7.y:4298
 		{
 			yyVAL.ClosureUse = nil
 
@@ -6850,7 +6895,7 @@ yydefault:
 		}
 	case 379:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4259
+		// line php7/php7.y:4304
 		{
 			yyVAL.ClosureUse = expr.NewClosureUse(yyDollar[3].list)
 
@@ -6866,7 +6911,7 @@ yydefault:
 		}
 	case 380:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4276
+		// line php7/php7.y:4321
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[3].node)
 
@@ -6877,7 +6922,7 @@ yydefault:
 		}
 	case 381:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4285
+		// line php7/php7.y:4330
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -6885,7 +6930,7 @@ yydefault:
 		}
 	case 382:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4294
+		// line php7/php7.y:4339
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(identifier)
@@ -6902,7 +6947,7 @@ yydefault:
 		}
 	case 383:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4309
+		// line php7/php7.y:4354
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[2].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -6922,7 +6967,7 @@ yydefault:
 		}
 	case 384:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4330
+		// line php7/php7.y:4375
 		{
 			yyVAL.node = expr.NewFunctionCall(yyDollar[1].node, yyDollar[2].node.(*node.ArgumentList))
 
@@ -6936,7 +6981,7 @@ yydefault:
 		}
 	case 385:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4342
+		// line php7/php7.y:4387
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -6951,7 +6996,7 @@ yydefault:
 		}
 	case 386:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4355
+		// line php7/php7.y:4400
 		{
 			yyVAL.node = expr.NewStaticCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -6966,7 +7011,7 @@ yydefault:
 		}
 	case 387:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4368
+		// line php7/php7.y:4413
 		{
 			yyVAL.node = expr.NewFunctionCall(yyDollar[1].node, yyDollar[2].node.(*node.ArgumentList))
 
@@ -6980,7 +7025,7 @@ yydefault:
 		}
 	case 388:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4383
+		// line php7/php7.y:4428
 		{
 			yyVAL.node = node.NewIdentifier(yyDollar[1].token.Value)
 
@@ -6994,7 +7039,7 @@ yydefault:
 		}
 	case 389:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4395
+		// line php7/php7.y:4440
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7002,7 +7047,7 @@ yydefault:
 		}
 	case 390:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4404
+		// line php7/php7.y:4449
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7010,7 +7055,7 @@ yydefault:
 		}
 	case 391:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4410
+		// line php7/php7.y:4455
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7018,7 +7063,7 @@ yydefault:
 		}
 	case 392:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4419
+		// line php7/php7.y:4464
 		{
 			yyVAL.node = nil
 
@@ -7026,7 +7071,7 @@ yydefault:
 		}
 	case 393:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4425
+		// line php7/php7.y:4470
 		{
 			yyVAL.node = expr.NewExit(yyDollar[2].node)
 
@@ -7041,7 +7086,7 @@ yydefault:
 		}
 	case 394:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4441
+		// line php7/php7.y:4486
 		{
 			yyVAL.list = []node.Node{}
 
@@ -7049,7 +7094,7 @@ yydefault:
 		}
 	case 395:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4447
+		// line php7/php7.y:4492
 		{
 			part := scalar.NewEncapsedStringPart(yyDollar[1].token.Value)
 			yyVAL.list = []node.Node{part}
@@ -7061,7 +7106,7 @@ yydefault:
 		}
 	case 396:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4457
+		// line php7/php7.y:4502
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -7069,7 +7114,7 @@ yydefault:
 		}
 	case 397:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4466
+		// line php7/php7.y:4511
 		{
 			yyVAL.node = nil
 
@@ -7077,7 +7122,7 @@ yydefault:
 		}
 	case 398:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4472
+		// line php7/php7.y:4517
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7085,7 +7130,7 @@ yydefault:
 		}
 	case 399:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4481
+		// line php7/php7.y:4526
 		{
 			yyVAL.node = expr.NewArray(yyDollar[3].list)
 
@@ -7101,7 +7146,7 @@ yydefault:
 		}
 	case 400:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4495
+		// line php7/php7.y:4540
 		{
 			yyVAL.node = expr.NewShortArray(yyDollar[2].list)
 
@@ -7116,7 +7161,7 @@ yydefault:
 		}
 	case 401:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4508
+		// line php7/php7.y:4553
 		{
 			yyVAL.node = scalar.NewString(yyDollar[1].token.Value)
 
@@ -7130,7 +7175,7 @@ yydefault:
 		}
 	case 402:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4523
+		// line php7/php7.y:4568
 		{
 			yyVAL.node = scalar.NewLnumber(yyDollar[1].token.Value)
 
@@ -7144,7 +7189,7 @@ yydefault:
 		}
 	case 403:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4535
+		// line php7/php7.y:4580
 		{
 			yyVAL.node = scalar.NewDnumber(yyDollar[1].token.Value)
 
@@ -7158,7 +7203,7 @@ yydefault:
 		}
 	case 404:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4547
+		// line php7/php7.y:4592
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7172,7 +7217,7 @@ yydefault:
 		}
 	case 405:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4559
+		// line php7/php7.y:4604
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7186,7 +7231,7 @@ yydefault:
 		}
 	case 406:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4571
+		// line php7/php7.y:4616
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7200,7 +7245,7 @@ yydefault:
 		}
 	case 407:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4583
+		// line php7/php7.y:4628
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7214,7 +7259,7 @@ yydefault:
 		}
 	case 408:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4595
+		// line php7/php7.y:4640
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7228,7 +7273,7 @@ yydefault:
 		}
 	case 409:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4607
+		// line php7/php7.y:4652
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7242,7 +7287,7 @@ yydefault:
 		}
 	case 410:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4619
+		// line php7/php7.y:4664
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7256,7 +7301,7 @@ yydefault:
 		}
 	case 411:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4631
+		// line php7/php7.y:4676
 		{
 			yyVAL.node = scalar.NewMagicConstant(yyDollar[1].token.Value)
 
@@ -7270,7 +7315,7 @@ yydefault:
 		}
 	case 412:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4643
+		// line php7/php7.y:4688
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[2].token.Value)
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, []node.Node{encapsed})
@@ -7286,7 +7331,7 @@ yydefault:
 		}
 	case 413:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4657
+		// line php7/php7.y:4702
 		{
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, nil)
 
@@ -7300,7 +7345,7 @@ yydefault:
 		}
 	case 414:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4669
+		// line php7/php7.y:4714
 		{
 			yyVAL.node = scalar.NewEncapsed(yyDollar[2].list)
 
@@ -7314,7 +7359,7 @@ yydefault:
 		}
 	case 415:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4681
+		// line php7/php7.y:4726
 		{
 			yyVAL.node = scalar.NewHeredoc(yyDollar[1].token.Value, yyDollar[2].list)
 
@@ -7328,7 +7373,7 @@ yydefault:
 		}
 	case 416:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4693
+		// line php7/php7.y:4738
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7336,7 +7381,7 @@ yydefault:
 		}
 	case 417:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4699
+		// line php7/php7.y:4744
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7344,7 +7389,7 @@ yydefault:
 		}
 	case 418:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4708
+		// line php7/php7.y:4753
 		{
 			yyVAL.node = expr.NewConstFetch(yyDollar[1].node)
 
@@ -7358,7 +7403,7 @@ yydefault:
 		}
 	case 419:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4720
+		// line php7/php7.y:4765
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -7376,7 +7421,7 @@ yydefault:
 		}
 	case 420:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4736
+		// line php7/php7.y:4781
 		{
 			target := node.NewIdentifier(yyDollar[3].token.Value)
 			yyVAL.node = expr.NewClassConstFetch(yyDollar[1].node, target)
@@ -7394,7 +7439,7 @@ yydefault:
 		}
 	case 421:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4755
+		// line php7/php7.y:4800
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7402,7 +7447,7 @@ yydefault:
 		}
 	case 422:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4761
+		// line php7/php7.y:4806
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7410,7 +7455,7 @@ yydefault:
 		}
 	case 423:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:4770
+		// line php7/php7.y:4815
 		{
 			yyVAL.node = nil
 
@@ -7418,7 +7463,7 @@ yydefault:
 		}
 	case 424:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4776
+		// line php7/php7.y:4821
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7426,7 +7471,7 @@ yydefault:
 		}
 	case 425:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4785
+		// line php7/php7.y:4830
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7434,7 +7479,7 @@ yydefault:
 		}
 	case 426:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4794
+		// line php7/php7.y:4839
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7442,7 +7487,7 @@ yydefault:
 		}
 	case 427:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4800
+		// line php7/php7.y:4845
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -7454,7 +7499,7 @@ yydefault:
 		}
 	case 428:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4810
+		// line php7/php7.y:4855
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7462,7 +7507,7 @@ yydefault:
 		}
 	case 429:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4819
+		// line php7/php7.y:4864
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7470,7 +7515,7 @@ yydefault:
 		}
 	case 430:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4825
+		// line php7/php7.y:4870
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -7482,7 +7527,7 @@ yydefault:
 		}
 	case 431:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4835
+		// line php7/php7.y:4880
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7490,7 +7535,7 @@ yydefault:
 		}
 	case 432:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4844
+		// line php7/php7.y:4889
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7498,7 +7543,7 @@ yydefault:
 		}
 	case 433:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4850
+		// line php7/php7.y:4895
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7514,7 +7559,7 @@ yydefault:
 		}
 	case 434:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4864
+		// line php7/php7.y:4909
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7530,7 +7575,7 @@ yydefault:
 		}
 	case 435:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4878
+		// line php7/php7.y:4923
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7546,7 +7591,7 @@ yydefault:
 		}
 	case 436:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4892
+		// line php7/php7.y:4937
 		{
 			yyVAL.node = expr.NewMethodCall(yyDollar[1].node, yyDollar[3].node, yyDollar[4].node.(*node.ArgumentList))
 
@@ -7561,7 +7606,7 @@ yydefault:
 		}
 	case 437:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4905
+		// line php7/php7.y:4950
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7569,7 +7614,7 @@ yydefault:
 		}
 	case 438:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4914
+		// line php7/php7.y:4959
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7577,7 +7622,7 @@ yydefault:
 		}
 	case 439:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4920
+		// line php7/php7.y:4965
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7585,7 +7630,7 @@ yydefault:
 		}
 	case 440:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4926
+		// line php7/php7.y:4971
 		{
 			yyVAL.node = expr.NewPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7600,7 +7645,7 @@ yydefault:
 		}
 	case 441:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:4942
+		// line php7/php7.y:4987
 		{
 			name := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(name)
@@ -7617,7 +7662,7 @@ yydefault:
 		}
 	case 442:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:4957
+		// line php7/php7.y:5002
 		{
 			yyVAL.node = expr.NewVariable(yyDollar[3].node)
 
@@ -7634,7 +7679,7 @@ yydefault:
 		}
 	case 443:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:4972
+		// line php7/php7.y:5017
 		{
 			yyVAL.node = expr.NewVariable(yyDollar[2].node)
 
@@ -7649,7 +7694,7 @@ yydefault:
 		}
 	case 444:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:4988
+		// line php7/php7.y:5033
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7664,7 +7709,7 @@ yydefault:
 		}
 	case 445:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5001
+		// line php7/php7.y:5046
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7679,7 +7724,7 @@ yydefault:
 		}
 	case 446:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5017
+		// line php7/php7.y:5062
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7687,7 +7732,7 @@ yydefault:
 		}
 	case 447:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5023
+		// line php7/php7.y:5068
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7703,7 +7748,7 @@ yydefault:
 		}
 	case 448:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5037
+		// line php7/php7.y:5082
 		{
 			yyVAL.node = expr.NewArrayDimFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7719,7 +7764,7 @@ yydefault:
 		}
 	case 449:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5051
+		// line php7/php7.y:5096
 		{
 			yyVAL.node = expr.NewPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7734,7 +7779,7 @@ yydefault:
 		}
 	case 450:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5064
+		// line php7/php7.y:5109
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7749,7 +7794,7 @@ yydefault:
 		}
 	case 451:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5077
+		// line php7/php7.y:5122
 		{
 			yyVAL.node = expr.NewStaticPropertyFetch(yyDollar[1].node, yyDollar[3].node)
 
@@ -7764,7 +7809,7 @@ yydefault:
 		}
 	case 452:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5093
+		// line php7/php7.y:5138
 		{
 			yyVAL.node = node.NewIdentifier(yyDollar[1].token.Value)
 
@@ -7778,7 +7823,7 @@ yydefault:
 		}
 	case 453:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5105
+		// line php7/php7.y:5150
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -7790,7 +7835,7 @@ yydefault:
 		}
 	case 454:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5115
+		// line php7/php7.y:5160
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7798,7 +7843,7 @@ yydefault:
 		}
 	case 455:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5124
+		// line php7/php7.y:5169
 		{
 			yyVAL.node = node.NewIdentifier(yyDollar[1].token.Value)
 
@@ -7812,7 +7857,7 @@ yydefault:
 		}
 	case 456:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5136
+		// line php7/php7.y:5181
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -7824,7 +7869,7 @@ yydefault:
 		}
 	case 457:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5146
+		// line php7/php7.y:5191
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7832,7 +7877,7 @@ yydefault:
 		}
 	case 458:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5155
+		// line php7/php7.y:5200
 		{
 			yyVAL.list = yyDollar[1].list
 
@@ -7840,7 +7885,7 @@ yydefault:
 		}
 	case 459:
 		yyDollar = yyS[yypt-0 : yypt+1]
-		// line php7/php7.y:5164
+		// line php7/php7.y:5209
 		{
 			yyVAL.node = expr.NewArrayItem(nil, nil, false)
 
@@ -7848,7 +7893,7 @@ yydefault:
 		}
 	case 460:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5170
+		// line php7/php7.y:5215
 		{
 			yyVAL.node = yyDollar[1].node
 
@@ -7856,7 +7901,7 @@ yydefault:
 		}
 	case 461:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5179
+		// line php7/php7.y:5224
 		{
 			if len(yyDollar[1].list) == 0 {
 				yyDollar[1].list = []node.Node{expr.NewArrayItem(nil, nil, false)}
@@ -7871,7 +7916,7 @@ yydefault:
 		}
 	case 462:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5192
+		// line php7/php7.y:5237
 		{
 			if yyDollar[1].node.(*expr.ArrayItem).Key == nil && yyDollar[1].node.(*expr.ArrayItem).Val == nil {
 				yyVAL.list = []node.Node{}
@@ -7883,7 +7928,7 @@ yydefault:
 		}
 	case 463:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5205
+		// line php7/php7.y:5250
 		{
 			yyVAL.node = expr.NewArrayItem(yyDollar[1].node, yyDollar[3].node, false)
 
@@ -7898,7 +7943,7 @@ yydefault:
 		}
 	case 464:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5218
+		// line php7/php7.y:5263
 		{
 			yyVAL.node = expr.NewArrayItem(nil, yyDollar[1].node, false)
 
@@ -7912,7 +7957,7 @@ yydefault:
 		}
 	case 465:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5230
+		// line php7/php7.y:5275
 		{
 			reference := expr.NewReference(yyDollar[4].node)
 			yyVAL.node = expr.NewArrayItem(yyDollar[1].node, reference, false)
@@ -7930,7 +7975,7 @@ yydefault:
 		}
 	case 466:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5246
+		// line php7/php7.y:5291
 		{
 			reference := expr.NewReference(yyDollar[2].node)
 			yyVAL.node = expr.NewArrayItem(nil, reference, false)
@@ -7946,7 +7991,7 @@ yydefault:
 		}
 	case 467:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5260
+		// line php7/php7.y:5305
 		{
 			yyVAL.node = expr.NewArrayItem(nil, yyDollar[2].node, true)
 
@@ -7960,7 +8005,7 @@ yydefault:
 		}
 	case 468:
 		yyDollar = yyS[yypt-6 : yypt+1]
-		// line php7/php7.y:5272
+		// line php7/php7.y:5317
 		{
 			// TODO: Cannot use list() as standalone expression
 			listNode := expr.NewList(yyDollar[5].list)
@@ -7981,7 +8026,7 @@ yydefault:
 		}
 	case 469:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5291
+		// line php7/php7.y:5336
 		{
 			// TODO: Cannot use list() as standalone expression
 			listNode := expr.NewList(yyDollar[3].list)
@@ -8000,7 +8045,7 @@ yydefault:
 		}
 	case 470:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5311
+		// line php7/php7.y:5356
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[2].node)
 
@@ -8008,7 +8053,7 @@ yydefault:
 		}
 	case 471:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5317
+		// line php7/php7.y:5362
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[2].token.Value)
 			yyVAL.list = append(yyDollar[1].list, encapsed)
@@ -8023,7 +8068,7 @@ yydefault:
 		}
 	case 472:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5330
+		// line php7/php7.y:5375
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -8031,7 +8076,7 @@ yydefault:
 		}
 	case 473:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5336
+		// line php7/php7.y:5381
 		{
 			encapsed := scalar.NewEncapsedStringPart(yyDollar[1].token.Value)
 			yyVAL.list = []node.Node{encapsed, yyDollar[2].node}
@@ -8046,7 +8091,7 @@ yydefault:
 		}
 	case 474:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5352
+		// line php7/php7.y:5397
 		{
 			name := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(name)
@@ -8063,7 +8108,7 @@ yydefault:
 		}
 	case 475:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5367
+		// line php7/php7.y:5412
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -8083,7 +8128,7 @@ yydefault:
 		}
 	case 476:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5385
+		// line php7/php7.y:5430
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			variable := expr.NewVariable(identifier)
@@ -8105,7 +8150,7 @@ yydefault:
 		}
 	case 477:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5405
+		// line php7/php7.y:5450
 		{
 			variable := expr.NewVariable(yyDollar[2].node)
 
@@ -8122,7 +8167,7 @@ yydefault:
 		}
 	case 478:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5420
+		// line php7/php7.y:5465
 		{
 			name := node.NewIdentifier(yyDollar[2].token.Value)
 			variable := expr.NewVariable(name)
@@ -8141,7 +8186,7 @@ yydefault:
 		}
 	case 479:
 		yyDollar = yyS[yypt-6 : yypt+1]
-		// line php7/php7.y:5437
+		// line php7/php7.y:5482
 		{
 			identifier := node.NewIdentifier(yyDollar[2].token.Value)
 			variable := expr.NewVariable(identifier)
@@ -8162,7 +8207,7 @@ yydefault:
 		}
 	case 480:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5456
+		// line php7/php7.y:5501
 		{
 			yyVAL.node = yyDollar[2].node
 
@@ -8174,7 +8219,7 @@ yydefault:
 		}
 	case 481:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5469
+		// line php7/php7.y:5514
 		{
 			yyVAL.node = scalar.NewString(yyDollar[1].token.Value)
 
@@ -8188,7 +8233,7 @@ yydefault:
 		}
 	case 482:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5481
+		// line php7/php7.y:5526
 		{
 			// TODO: add option to handle 64 bit integer
 			if _, err := strconv.Atoi(yyDollar[1].token.Value); err == nil {
@@ -8207,7 +8252,7 @@ yydefault:
 		}
 	case 483:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5498
+		// line php7/php7.y:5543
 		{
 			var lnumber *scalar.Lnumber
 			// TODO: add option to handle 64 bit integer
@@ -8235,7 +8280,7 @@ yydefault:
 		}
 	case 484:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5524
+		// line php7/php7.y:5569
 		{
 			identifier := node.NewIdentifier(strings.TrimLeftFunc(yyDollar[1].token.Value, isDollar))
 			yyVAL.node = expr.NewVariable(identifier)
@@ -8252,7 +8297,7 @@ yydefault:
 		}
 	case 485:
 		yyDollar = yyS[yypt-5 : yypt+1]
-		// line php7/php7.y:5542
+		// line php7/php7.y:5587
 		{
 			yyVAL.node = expr.NewIsset(yyDollar[3].list)
 
@@ -8272,7 +8317,7 @@ yydefault:
 		}
 	case 486:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5560
+		// line php7/php7.y:5605
 		{
 			yyVAL.node = expr.NewEmpty(yyDollar[3].node)
 
@@ -8288,7 +8333,7 @@ yydefault:
 		}
 	case 487:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5574
+		// line php7/php7.y:5619
 		{
 			yyVAL.node = expr.NewInclude(yyDollar[2].node)
 
@@ -8302,7 +8347,7 @@ yydefault:
 		}
 	case 488:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5586
+		// line php7/php7.y:5631
 		{
 			yyVAL.node = expr.NewIncludeOnce(yyDollar[2].node)
 
@@ -8316,7 +8361,7 @@ yydefault:
 		}
 	case 489:
 		yyDollar = yyS[yypt-4 : yypt+1]
-		// line php7/php7.y:5598
+		// line php7/php7.y:5643
 		{
 			yyVAL.node = expr.NewEval(yyDollar[3].node)
 
@@ -8332,7 +8377,7 @@ yydefault:
 		}
 	case 490:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5612
+		// line php7/php7.y:5657
 		{
 			yyVAL.node = expr.NewRequire(yyDollar[2].node)
 
@@ -8346,7 +8391,7 @@ yydefault:
 		}
 	case 491:
 		yyDollar = yyS[yypt-2 : yypt+1]
-		// line php7/php7.y:5624
+		// line php7/php7.y:5669
 		{
 			yyVAL.node = expr.NewRequireOnce(yyDollar[2].node)
 
@@ -8360,7 +8405,7 @@ yydefault:
 		}
 	case 492:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5639
+		// line php7/php7.y:5684
 		{
 			yyVAL.list = []node.Node{yyDollar[1].node}
 
@@ -8368,7 +8413,7 @@ yydefault:
 		}
 	case 493:
 		yyDollar = yyS[yypt-3 : yypt+1]
-		// line php7/php7.y:5645
+		// line php7/php7.y:5690
 		{
 			yyVAL.list = append(yyDollar[1].list, yyDollar[3].node)
 
@@ -8379,7 +8424,7 @@ yydefault:
 		}
 	case 494:
 		yyDollar = yyS[yypt-1 : yypt+1]
-		// line php7/php7.y:5657
+		// line php7/php7.y:5702
 		{
 			yyVAL.node = yyDollar[1].node
 
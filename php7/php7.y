@@ -3217,6 +3217,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable '=' '&' expr
@@ -3231,6 +3234,9 @@ expr_without_variable:
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Equal, $3.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   T_CLONE expr
@@ -3256,6 +3262,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MINUS_EQUAL expr
@@ -3269,6 +3278,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MUL_EQUAL expr
@@ -3282,6 +3294,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_POW_EQUAL expr
@@ -3295,6 +3310,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_DIV_EQUAL expr
@@ -3308,6 +3326,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_CONCAT_EQUAL expr
@@ -3321,6 +3342,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_MOD_EQUAL expr
@@ -3334,6 +3358,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_AND_EQUAL expr
@@ -3347,6 +3374,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_OR_EQUAL expr
@@ -3360,6 +3390,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_XOR_EQUAL expr
@@ -3373,6 +3406,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_SL_EQUAL expr
@@ -3386,6 +3422,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_SR_EQUAL expr
@@ -3399,6 +3438,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_COALESCE_EQUAL expr
@@ -3412,6 +3454,9 @@ expr_without_variable:
                 yylex.(*Parser).MoveFreeFloating($1, $$)
                 yylex.(*Parser).setFreeFloating($$, freefloating.Var, $2.FreeFloating)
 
+                // check assign target
+                yylex.(*Parser).checkAssignTarget($1)
+
                 yylex.(*Parser).returnTokenToPool(yyDollar, &yyVAL)
             }
     |   variable T_INC
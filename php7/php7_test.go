@@ -16448,3 +16448,39 @@ func TestPhp7ControlCharsErrors(t *testing.T) {
 	actual := php7parser.GetErrors()
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestPhp7AssignToCallErrors(t *testing.T) {
+	src := `<?php foo() = 1; $a->b() = 1; A::c() += 1; $a = 1;`
+
+	expected := []*errors.Error{
+		{
+			Msg: "Can't use function return value in write context",
+			Pos: &position.Position{1, 1, 6, 11},
+		},
+		{
+			Msg: "Can't use method return value in write context",
+			Pos: &position.Position{1, 1, 17, 24},
+		},
+		{
+			Msg: "Can't use method return value in write context",
+			Pos: &position.Position{1, 1, 30, 36},
+		},
+	}
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	actual := php7parser.GetErrors()
+	assert.DeepEqual(t, expected, actual)
+}
+
+func TestPhp7ArrowFunctionVersion(t *testing.T) {
+	src := `<? fn($a) => $a;`
+
+	php7parser := php7.NewParser([]byte(src), "7.3")
+	php7parser.Parse()
+	assert.Assert(t, len(php7parser.GetErrors()) > 0)
+
+	php7parser = php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	assert.Equal(t, 0, len(php7parser.GetErrors()))
+}
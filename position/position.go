@@ -25,3 +25,40 @@ func NewPosition(StartLine int, EndLine int, StartPos int, EndPos int) *Position
 func (p Position) String() string {
 	return fmt.Sprintf("Pos{Line: %d-%d Pos: %d-%d}", p.StartLine, p.EndLine, p.StartPos, p.EndPos)
 }
+
+// Len returns the length of the position in bytes
+func (p Position) Len() int {
+	return p.EndPos - p.StartPos
+}
+
+// Contains reports whether the byte offset lies within the position
+func (p Position) Contains(offset int) bool {
+	return offset >= p.StartPos && offset < p.EndPos
+}
+
+// ContainsPosition reports whether o lies entirely within the position
+func (p Position) ContainsPosition(o *Position) bool {
+	return o.StartPos >= p.StartPos && o.EndPos <= p.EndPos
+}
+
+// Merge returns a new Position covering both p and o
+func (p Position) Merge(o *Position) *Position {
+	merged := &Position{
+		StartLine: p.StartLine,
+		EndLine:   p.EndLine,
+		StartPos:  p.StartPos,
+		EndPos:    p.EndPos,
+	}
+
+	if o.StartPos < merged.StartPos {
+		merged.StartLine = o.StartLine
+		merged.StartPos = o.StartPos
+	}
+
+	if o.EndPos > merged.EndPos {
+		merged.EndLine = o.EndLine
+		merged.EndPos = o.EndPos
+	}
+
+	return merged
+}
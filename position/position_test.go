@@ -17,3 +17,68 @@ func TestPrintPosition(t *testing.T) {
 		t.Errorf("expected and actual are not equal\n")
 	}
 }
+
+func TestPositionLen(t *testing.T) {
+	if l := position.NewPosition(1, 1, 2, 5).Len(); l != 3 {
+		t.Errorf("expected 3, got %d\n", l)
+	}
+
+	if l := position.NewPosition(1, 1, 2, 2).Len(); l != 0 {
+		t.Errorf("expected 0, got %d\n", l)
+	}
+}
+
+func TestPositionContains(t *testing.T) {
+	pos := position.NewPosition(1, 1, 2, 5)
+
+	for offset, expected := range map[int]bool{1: false, 2: true, 4: true, 5: false} {
+		if actual := pos.Contains(offset); actual != expected {
+			t.Errorf("offset %d: expected %v, got %v\n", offset, expected, actual)
+		}
+	}
+
+	if position.NewPosition(1, 1, 2, 2).Contains(2) {
+		t.Errorf("empty position must not contain any offset\n")
+	}
+}
+
+func TestPositionContainsPosition(t *testing.T) {
+	pos := position.NewPosition(1, 2, 2, 10)
+
+	if !pos.ContainsPosition(position.NewPosition(1, 1, 3, 5)) {
+		t.Errorf("expected inner position to be contained\n")
+	}
+
+	if !pos.ContainsPosition(pos) {
+		t.Errorf("expected position to contain itself\n")
+	}
+
+	if !pos.ContainsPosition(position.NewPosition(1, 1, 10, 10)) {
+		t.Errorf("expected empty position at the end to be contained\n")
+	}
+
+	if pos.ContainsPosition(position.NewPosition(1, 2, 5, 11)) {
+		t.Errorf("expected overlapping position not to be contained\n")
+	}
+}
+
+func TestPositionMerge(t *testing.T) {
+	a := position.NewPosition(2, 3, 10, 20)
+	b := position.NewPosition(1, 2, 5, 15)
+
+	expected := "Pos{Line: 1-3 Pos: 5-20}"
+
+	if actual := a.Merge(b).String(); actual != expected {
+		t.Errorf("expected %s, got %s\n", expected, actual)
+	}
+
+	if actual := b.Merge(a).String(); actual != expected {
+		t.Errorf("expected %s, got %s\n", expected, actual)
+	}
+
+	expected = "Pos{Line: 2-3 Pos: 10-20}"
+
+	if actual := a.Merge(position.NewPosition(2, 2, 12, 12)).String(); actual != expected {
+		t.Errorf("expected %s, got %s\n", expected, actual)
+	}
+}
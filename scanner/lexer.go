@@ -2,6 +2,7 @@ package scanner
 
 import (
 	"bytes"
+	"fmt"
 	"strings"
 
 	"github.com/z7zmey/php-parser/errors"
@@ -146,6 +147,16 @@ func (lex *Lexer) isHeredocEnd(p int) bool {
 	return lex.isHeredocEndSince73(p)
 }
 
+// keywordSince returns tok if the target PHP version knows the keyword, T_STRING otherwise
+func (lex *Lexer) keywordSince(tok TokenID, v string) TokenID {
+	r, err := version.Compare(lex.PHPVersion, v)
+	if err == nil && r == -1 {
+		return T_STRING
+	}
+
+	return tok
+}
+
 func (lex *Lexer) isHeredocEndBefore73(p int) bool {
 	if lex.data[p-1] != '\r' && lex.data[p-1] != '\n' {
 		return false
@@ -266,6 +277,62 @@ func (lex *Lexer) Error(msg string) {
 	lex.Errors = append(lex.Errors, errors.NewError(msg, pos))
 }
 
+// errorUnexpectedChars reports a run of consecutive control characters as a single error
+func (lex *Lexer) errorUnexpectedChars() {
+	for lex.te < lex.pe && isControlChar(lex.data[lex.te]) && isControlChar(lex.data[lex.p]) {
+		lex.te++
+		lex.p++
+	}
+
+	if lex.te-lex.ts == 1 {
+		c := lex.data[lex.p]
+		lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+		return
+	}
+
+	lex.Error(fmt.Sprintf("WARNING: Unexpected characters in input: %q", lex.data[lex.ts:lex.te]))
+}
+
+// isUnterminatedComment reports whether the '/' operator token starts
+// a "/*" comment that is not closed before the end of input
+func (lex *Lexer) isUnterminatedComment() bool {
+	return lex.te-lex.ts == 1 && lex.data[lex.ts] == '/' && lex.te < lex.pe && lex.data[lex.te] == '*'
+}
+
+// errorUnterminatedComment treats the rest of input as a comment
+func (lex *Lexer) errorUnterminatedComment() {
+	lex.skipToEnd()
+	lex.addFreeFloating(freefloating.CommentType, lex.ts, lex.te)
+	lex.Error(fmt.Sprintf("WARNING: Unterminated comment starting line %d", lex.NewLines.GetLine(lex.ts)))
+}
+
+// errorUnterminatedString reports a string that is not closed before the end of input,
+// the rest of input is treated as its content
+func (lex *Lexer) errorUnterminatedString() {
+	lex.skipToEnd()
+	lex.Error("WARNING: Unterminated string")
+}
+
+func (lex *Lexer) skipToEnd() {
+	for i := lex.te; i < lex.pe; i++ {
+		if lex.data[i] == '\n' || (lex.data[i] == '\r' && (i+1 == lex.pe || lex.data[i+1] != '\n')) {
+			lex.NewLines.Append(i + 1)
+		}
+	}
+
+	lex.te = lex.pe
+	lex.p = lex.pe - 1
+}
+
+func isControlChar(r byte) bool {
+	switch r {
+	case '\t', '\n', '\v', '\f', '\r':
+		return false
+	}
+
+	return r < 0x20 || r == 0x7f
+}
+
 func isValidVarNameStart(r byte) bool {
 	return (r >= 'A' && r <= 'Z') || (r >= 'a' && r <= 'z') || r == '_' || (r >= 0x80 && r <= 0xff)
 }
@@ -143,3 +143,52 @@ const (
 	T_IS_SMALLER_OR_EQUAL
 	T_IS_GREATER_OR_EQUAL
 )
+
+// IsKeyword reports whether the token is a keyword of the php7 (PHP 7.4) grammar.
+// The set includes magic constants such as T_LINE and T_CLASS_C, and T_FN,
+// which the lexer only emits for PHP 7.4 and newer.
+func (t TokenID) IsKeyword() bool {
+	return t == T_HALT_COMPILER || t.IsSemiReserved()
+}
+
+// IsSemiReserved reports whether the token is a keyword that still may be used
+// as a class constant, property or method name since PHP 7.0
+func (t TokenID) IsSemiReserved() bool {
+	return t.isReservedNonModifier() || t.IsModifier()
+}
+
+// IsModifier reports whether the token is a class member modifier
+func (t TokenID) IsModifier() bool {
+	switch t {
+	case T_STATIC, T_ABSTRACT, T_FINAL, T_PRIVATE, T_PROTECTED, T_PUBLIC:
+		return true
+	}
+
+	return false
+}
+
+// IsCast reports whether the token is a type cast
+func (t TokenID) IsCast() bool {
+	switch t {
+	case T_INT_CAST, T_DOUBLE_CAST, T_STRING_CAST, T_ARRAY_CAST, T_OBJECT_CAST, T_BOOL_CAST, T_UNSET_CAST:
+		return true
+	}
+
+	return false
+}
+
+// isReservedNonModifier matches the reserved_non_modifiers rule of the php7 grammar
+func (t TokenID) isReservedNonModifier() bool {
+	switch t {
+	case T_INCLUDE, T_INCLUDE_ONCE, T_EVAL, T_REQUIRE, T_REQUIRE_ONCE, T_LOGICAL_OR, T_LOGICAL_XOR, T_LOGICAL_AND,
+		T_INSTANCEOF, T_NEW, T_CLONE, T_EXIT, T_IF, T_ELSEIF, T_ELSE, T_ENDIF, T_ECHO, T_DO, T_WHILE, T_ENDWHILE,
+		T_FOR, T_ENDFOR, T_FOREACH, T_ENDFOREACH, T_DECLARE, T_ENDDECLARE, T_AS, T_TRY, T_CATCH, T_FINALLY,
+		T_THROW, T_USE, T_INSTEADOF, T_GLOBAL, T_VAR, T_UNSET, T_ISSET, T_EMPTY, T_CONTINUE, T_GOTO,
+		T_FUNCTION, T_CONST, T_RETURN, T_PRINT, T_YIELD, T_LIST, T_SWITCH, T_ENDSWITCH, T_CASE, T_DEFAULT, T_BREAK,
+		T_ARRAY, T_CALLABLE, T_EXTENDS, T_IMPLEMENTS, T_NAMESPACE, T_TRAIT, T_INTERFACE, T_CLASS,
+		T_CLASS_C, T_TRAIT_C, T_FUNC_C, T_METHOD_C, T_LINE, T_FILE, T_DIR, T_NS_C, T_FN:
+		return true
+	}
+
+	return false
+}
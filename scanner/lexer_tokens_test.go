@@ -0,0 +1,44 @@
+package scanner_test
+
+import (
+	"testing"
+
+	"gotest.tools/assert"
+
+	"github.com/z7zmey/php-parser/scanner"
+)
+
+func TestTokenIDIsKeyword(t *testing.T) {
+	assert.Assert(t, scanner.T_CLASS.IsKeyword())
+	assert.Assert(t, scanner.T_PUBLIC.IsKeyword())
+	assert.Assert(t, scanner.T_HALT_COMPILER.IsKeyword())
+
+	assert.Assert(t, !scanner.T_STRING.IsKeyword())
+	assert.Assert(t, !scanner.T_VARIABLE.IsKeyword())
+	assert.Assert(t, !scanner.T_INT_CAST.IsKeyword())
+}
+
+func TestTokenIDIsSemiReserved(t *testing.T) {
+	assert.Assert(t, scanner.T_LIST.IsSemiReserved())
+	assert.Assert(t, scanner.T_CLASS.IsSemiReserved())
+	assert.Assert(t, scanner.T_STATIC.IsSemiReserved())
+
+	assert.Assert(t, !scanner.T_HALT_COMPILER.IsSemiReserved())
+	assert.Assert(t, !scanner.T_STRING.IsSemiReserved())
+}
+
+func TestTokenIDIsModifier(t *testing.T) {
+	assert.Assert(t, scanner.T_ABSTRACT.IsModifier())
+	assert.Assert(t, scanner.T_PRIVATE.IsModifier())
+
+	assert.Assert(t, !scanner.T_CLASS.IsModifier())
+	assert.Assert(t, !scanner.T_VAR.IsModifier())
+}
+
+func TestTokenIDIsCast(t *testing.T) {
+	assert.Assert(t, scanner.T_INT_CAST.IsCast())
+	assert.Assert(t, scanner.T_UNSET_CAST.IsCast())
+
+	assert.Assert(t, !scanner.T_ARRAY.IsCast())
+	assert.Assert(t, !scanner.T_STRING.IsCast())
+}
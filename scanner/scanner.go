@@ -2,7 +2,6 @@
 package scanner
 
 import (
-	"fmt"
 	"strconv"
 	"strings"
 
@@ -31,7 +30,7 @@ const lexer_en_halt_compiller_close_parenthesis int = 518
 const lexer_en_halt_compiller_close_semicolon int = 522
 const lexer_en_halt_compiller_end int = 526
 
-// line scanner/scanner.rl:17
+// line scanner/scanner.rl:16
 
 func NewLexer(data []byte) *Lexer {
 	lex := &Lexer{
@@ -52,7 +51,7 @@ func NewLexer(data []byte) *Lexer {
 		lex.act = 0
 	}
 
-	// line scanner/scanner.rl:29
+	// line scanner/scanner.rl:28
 	return lex
 }
 
@@ -2207,7 +2206,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st_out
 	tr0:
 		lex.cs = 113
-		// line scanner/scanner.rl:141
+		// line scanner/scanner.rl:140
 		(lex.p) = (lex.te) - 1
 		{
 			lex.cs = 116
@@ -2216,7 +2215,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr166:
 		lex.cs = 113
-		// line scanner/scanner.rl:141
+		// line scanner/scanner.rl:140
 		lex.te = (lex.p) + 1
 		{
 			lex.cs = 116
@@ -2225,7 +2224,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr168:
 		lex.cs = 113
-		// line scanner/scanner.rl:141
+		// line scanner/scanner.rl:140
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2234,9 +2233,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr169:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:138
+		// line scanner/scanner.rl:137
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2291,7 +2290,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto tr169
 	tr3:
 		lex.cs = 116
-		// line scanner/scanner.rl:154
+		// line scanner/scanner.rl:153
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.TokenType, lex.ts, lex.te)
@@ -2300,7 +2299,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr6:
 		lex.cs = 116
-		// line scanner/scanner.rl:158
+		// line scanner/scanner.rl:157
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(lex.te - lex.ts - 5)
@@ -2309,7 +2308,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr173:
-		// line scanner/scanner.rl:148
+		// line scanner/scanner.rl:147
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2324,9 +2323,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st116
 	tr175:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:148
+		// line scanner/scanner.rl:147
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2342,7 +2341,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st116
 	tr180:
 		lex.cs = 116
-		// line scanner/scanner.rl:154
+		// line scanner/scanner.rl:153
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2352,7 +2351,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr181:
 		lex.cs = 116
-		// line scanner/scanner.rl:163
+		// line scanner/scanner.rl:162
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -2366,9 +2365,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr183:
 		lex.cs = 116
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:158
+		// line scanner/scanner.rl:157
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -2397,7 +2396,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st117
 	tr176:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st117
 	st117:
@@ -2414,7 +2413,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st117
 	tr177:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st118
 	st118:
@@ -2431,7 +2430,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr176
 	tr178:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st119
 	st119:
@@ -2539,7 +2538,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr3
 	tr9:
-		// line scanner/scanner.rl:172
+		// line scanner/scanner.rl:171
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.WhiteSpaceType, lex.ts, lex.te)
@@ -2917,7 +2916,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 			{
 				(lex.p) = (lex.te) - 1
 				lex.setTokenPosition(token)
-				tok = T_FN
+				tok = lex.keywordSince(T_FN, "7.4")
 				{
 					(lex.p)++
 					goto _out
@@ -3359,7 +3358,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 
 		goto _again
 	tr14:
-		// line scanner/scanner.rl:361
+		// line scanner/scanner.rl:364
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3372,30 +3371,37 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr18:
-		// line scanner/scanner.rl:384
+		// line scanner/scanner.rl:387
 		(lex.p) = (lex.te) - 1
 		{
-			c := lex.data[lex.p]
-			lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+			if lex.data[lex.ts] == '\'' {
+				lex.errorUnterminatedString()
+			} else {
+				lex.errorUnexpectedChars()
+			}
 		}
 		goto st123
 	tr22:
-		// line scanner/scanner.rl:346
+		// line scanner/scanner.rl:345
 		(lex.p) = (lex.te) - 1
 		{
-			// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
-			// tok = TokenID(Rune2Class(rune));
-			lex.setTokenPosition(token)
-			tok = TokenID(int(lex.data[lex.ts]))
-			{
-				(lex.p)++
-				lex.cs = 123
-				goto _out
+			if lex.isUnterminatedComment() {
+				lex.errorUnterminatedComment()
+			} else {
+				// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
+				// tok = TokenID(Rune2Class(rune));
+				lex.setTokenPosition(token)
+				tok = TokenID(int(lex.data[lex.ts]))
+				{
+					(lex.p)++
+					lex.cs = 123
+					goto _out
+				}
 			}
 		}
 		goto st123
 	tr37:
-		// line scanner/scanner.rl:322
+		// line scanner/scanner.rl:321
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3408,7 +3414,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr44:
-		// line scanner/scanner.rl:327
+		// line scanner/scanner.rl:326
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3421,7 +3427,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr48:
-		// line scanner/scanner.rl:323
+		// line scanner/scanner.rl:322
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3434,7 +3440,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr56:
-		// line scanner/scanner.rl:324
+		// line scanner/scanner.rl:323
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3447,7 +3453,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr63:
-		// line scanner/scanner.rl:325
+		// line scanner/scanner.rl:324
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3460,7 +3466,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr72:
-		// line scanner/scanner.rl:326
+		// line scanner/scanner.rl:325
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3473,7 +3479,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr83:
-		// line scanner/scanner.rl:328
+		// line scanner/scanner.rl:327
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3486,7 +3492,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr84:
-		// line scanner/scanner.rl:290
+		// line scanner/scanner.rl:289
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3499,7 +3505,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr87:
-		// line scanner/scanner.rl:176
+		// line scanner/scanner.rl:175
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -3512,7 +3518,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr95:
-		// line scanner/scanner.rl:334
+		// line scanner/scanner.rl:333
 		lex.te = (lex.p) + 1
 		{
 			isDocComment := false
@@ -3527,7 +3533,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr96:
-		// line scanner/scanner.rl:187
+		// line scanner/scanner.rl:186
 		(lex.p) = (lex.te) - 1
 		{
 			base := 10
@@ -3559,7 +3565,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr109:
 		lex.cs = 123
-		// line scanner/scanner.rl:174
+		// line scanner/scanner.rl:173
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -3573,7 +3579,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr125:
 		lex.cs = 123
-		// line scanner/scanner.rl:173
+		// line scanner/scanner.rl:172
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -3586,7 +3592,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr127:
-		// line scanner/scanner.rl:357
+		// line scanner/scanner.rl:360
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -3599,7 +3605,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr129:
-		// line scanner/scanner.rl:271
+		// line scanner/scanner.rl:270
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -3612,7 +3618,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr140:
-		// line scanner/scanner.rl:270
+		// line scanner/scanner.rl:269
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3625,30 +3631,37 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr184:
-		// line scanner/scanner.rl:384
+		// line scanner/scanner.rl:387
 		lex.te = (lex.p) + 1
 		{
-			c := lex.data[lex.p]
-			lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+			if lex.data[lex.ts] == '\'' {
+				lex.errorUnterminatedString()
+			} else {
+				lex.errorUnexpectedChars()
+			}
 		}
 		goto st123
 	tr195:
-		// line scanner/scanner.rl:346
+		// line scanner/scanner.rl:345
 		lex.te = (lex.p) + 1
 		{
-			// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
-			// tok = TokenID(Rune2Class(rune));
-			lex.setTokenPosition(token)
-			tok = TokenID(int(lex.data[lex.ts]))
-			{
-				(lex.p)++
-				lex.cs = 123
-				goto _out
+			if lex.isUnterminatedComment() {
+				lex.errorUnterminatedComment()
+			} else {
+				// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
+				// tok = TokenID(Rune2Class(rune));
+				lex.setTokenPosition(token)
+				tok = TokenID(int(lex.data[lex.ts]))
+				{
+					(lex.p)++
+					lex.cs = 123
+					goto _out
+				}
 			}
 		}
 		goto st123
 	tr229:
-		// line scanner/scanner.rl:289
+		// line scanner/scanner.rl:288
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3662,7 +3675,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr232:
 		lex.cs = 123
-		// line scanner/scanner.rl:381
+		// line scanner/scanner.rl:384
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3675,7 +3688,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr233:
-		// line scanner/scanner.rl:354
+		// line scanner/scanner.rl:357
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3685,7 +3698,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr235:
-		// line scanner/scanner.rl:355
+		// line scanner/scanner.rl:358
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3696,7 +3709,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr236:
-		// line scanner/scanner.rl:172
+		// line scanner/scanner.rl:171
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3704,9 +3717,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr238:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:172
+		// line scanner/scanner.rl:171
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3714,32 +3727,39 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr242:
-		// line scanner/scanner.rl:384
+		// line scanner/scanner.rl:387
 		lex.te = (lex.p)
 		(lex.p)--
 		{
-			c := lex.data[lex.p]
-			lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+			if lex.data[lex.ts] == '\'' {
+				lex.errorUnterminatedString()
+			} else {
+				lex.errorUnexpectedChars()
+			}
 		}
 		goto st123
 	tr243:
-		// line scanner/scanner.rl:346
+		// line scanner/scanner.rl:345
 		lex.te = (lex.p)
 		(lex.p)--
 		{
-			// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
-			// tok = TokenID(Rune2Class(rune));
-			lex.setTokenPosition(token)
-			tok = TokenID(int(lex.data[lex.ts]))
-			{
-				(lex.p)++
-				lex.cs = 123
-				goto _out
+			if lex.isUnterminatedComment() {
+				lex.errorUnterminatedComment()
+			} else {
+				// rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
+				// tok = TokenID(Rune2Class(rune));
+				lex.setTokenPosition(token)
+				tok = TokenID(int(lex.data[lex.ts]))
+				{
+					(lex.p)++
+					lex.cs = 123
+					goto _out
+				}
 			}
 		}
 		goto st123
 	tr245:
-		// line scanner/scanner.rl:308
+		// line scanner/scanner.rl:307
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3753,7 +3773,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr246:
-		// line scanner/scanner.rl:309
+		// line scanner/scanner.rl:308
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3767,7 +3787,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr247:
 		lex.cs = 123
-		// line scanner/scanner.rl:382
+		// line scanner/scanner.rl:385
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3781,7 +3801,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr248:
-		// line scanner/scanner.rl:330
+		// line scanner/scanner.rl:329
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3790,9 +3810,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr250:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:330
+		// line scanner/scanner.rl:329
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3801,7 +3821,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr254:
-		// line scanner/scanner.rl:356
+		// line scanner/scanner.rl:359
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3815,7 +3835,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr255:
-		// line scanner/scanner.rl:303
+		// line scanner/scanner.rl:302
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3828,7 +3848,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr256:
-		// line scanner/scanner.rl:292
+		// line scanner/scanner.rl:291
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3841,7 +3861,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr257:
-		// line scanner/scanner.rl:294
+		// line scanner/scanner.rl:293
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3854,7 +3874,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr259:
-		// line scanner/scanner.rl:297
+		// line scanner/scanner.rl:296
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3867,7 +3887,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr260:
-		// line scanner/scanner.rl:316
+		// line scanner/scanner.rl:315
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3881,7 +3901,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr261:
-		// line scanner/scanner.rl:298
+		// line scanner/scanner.rl:297
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3894,7 +3914,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr262:
-		// line scanner/scanner.rl:305
+		// line scanner/scanner.rl:304
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3907,7 +3927,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr263:
-		// line scanner/scanner.rl:300
+		// line scanner/scanner.rl:299
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3920,7 +3940,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr264:
-		// line scanner/scanner.rl:304
+		// line scanner/scanner.rl:303
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3933,7 +3953,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr265:
-		// line scanner/scanner.rl:301
+		// line scanner/scanner.rl:300
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3947,7 +3967,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr266:
 		lex.cs = 123
-		// line scanner/scanner.rl:359
+		// line scanner/scanner.rl:362
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3960,7 +3980,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr268:
-		// line scanner/scanner.rl:296
+		// line scanner/scanner.rl:295
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -3973,7 +3993,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr269:
-		// line scanner/scanner.rl:176
+		// line scanner/scanner.rl:175
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -3987,7 +4007,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr272:
-		// line scanner/scanner.rl:299
+		// line scanner/scanner.rl:298
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4000,7 +4020,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr273:
-		// line scanner/scanner.rl:187
+		// line scanner/scanner.rl:186
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4032,7 +4052,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr278:
-		// line scanner/scanner.rl:177
+		// line scanner/scanner.rl:176
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4059,7 +4079,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr279:
-		// line scanner/scanner.rl:202
+		// line scanner/scanner.rl:201
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4086,7 +4106,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr280:
-		// line scanner/scanner.rl:291
+		// line scanner/scanner.rl:290
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4100,7 +4120,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr281:
 		lex.cs = 123
-		// line scanner/scanner.rl:174
+		// line scanner/scanner.rl:173
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4115,9 +4135,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr283:
 		lex.cs = 123
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:174
+		// line scanner/scanner.rl:173
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4131,7 +4151,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr286:
-		// line scanner/scanner.rl:308
+		// line scanner/scanner.rl:307
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4144,7 +4164,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr287:
-		// line scanner/scanner.rl:317
+		// line scanner/scanner.rl:316
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4158,7 +4178,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr288:
-		// line scanner/scanner.rl:312
+		// line scanner/scanner.rl:311
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4172,9 +4192,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr289:
 		lex.cs = 123
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:367
+		// line scanner/scanner.rl:370
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4196,7 +4216,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr290:
-		// line scanner/scanner.rl:315
+		// line scanner/scanner.rl:314
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4210,7 +4230,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr291:
-		// line scanner/scanner.rl:307
+		// line scanner/scanner.rl:306
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4223,7 +4243,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr293:
-		// line scanner/scanner.rl:306
+		// line scanner/scanner.rl:305
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4236,7 +4256,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr294:
-		// line scanner/scanner.rl:310
+		// line scanner/scanner.rl:309
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4250,7 +4270,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr295:
-		// line scanner/scanner.rl:311
+		// line scanner/scanner.rl:310
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4263,7 +4283,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr296:
-		// line scanner/scanner.rl:314
+		// line scanner/scanner.rl:313
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4276,7 +4296,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr298:
-		// line scanner/scanner.rl:318
+		// line scanner/scanner.rl:317
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4290,7 +4310,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr299:
-		// line scanner/scanner.rl:313
+		// line scanner/scanner.rl:312
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4304,7 +4324,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st123
 	tr302:
 		lex.cs = 123
-		// line scanner/scanner.rl:173
+		// line scanner/scanner.rl:172
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4319,9 +4339,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr304:
 		lex.cs = 123
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:173
+		// line scanner/scanner.rl:172
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4335,7 +4355,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr305:
-		// line scanner/scanner.rl:319
+		// line scanner/scanner.rl:318
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4349,7 +4369,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr306:
-		// line scanner/scanner.rl:320
+		// line scanner/scanner.rl:319
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4362,7 +4382,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr307:
-		// line scanner/scanner.rl:357
+		// line scanner/scanner.rl:360
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4376,7 +4396,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr387:
-		// line scanner/scanner.rl:228
+		// line scanner/scanner.rl:227
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4390,7 +4410,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr407:
-		// line scanner/scanner.rl:232
+		// line scanner/scanner.rl:231
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4404,7 +4424,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr436:
-		// line scanner/scanner.rl:240
+		// line scanner/scanner.rl:239
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4418,7 +4438,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr440:
-		// line scanner/scanner.rl:242
+		// line scanner/scanner.rl:241
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4432,7 +4452,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr472:
-		// line scanner/scanner.rl:272
+		// line scanner/scanner.rl:271
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4446,7 +4466,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr540:
-		// line scanner/scanner.rl:274
+		// line scanner/scanner.rl:273
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4460,7 +4480,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr586:
-		// line scanner/scanner.rl:271
+		// line scanner/scanner.rl:270
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -4474,7 +4494,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr591:
-		// line scanner/scanner.rl:302
+		// line scanner/scanner.rl:301
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4487,7 +4507,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr665:
-		// line scanner/scanner.rl:295
+		// line scanner/scanner.rl:294
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4500,7 +4520,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st123
 	tr666:
-		// line scanner/scanner.rl:293
+		// line scanner/scanner.rl:292
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -4708,7 +4728,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st124
 	st124:
@@ -4738,7 +4758,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st125
 	st125:
@@ -4760,7 +4780,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr238
 	tr241:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st6
 	st6:
@@ -4804,7 +4824,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:382
+		// line scanner/scanner.rl:385
 		lex.act = 140
 		goto st129
 	st129:
@@ -4829,7 +4849,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st7
 	tr13:
-		// line scanner/scanner.rl:50
+		// line scanner/scanner.rl:49
 
 		if lex.data[lex.p] == '\n' {
 			lex.NewLines.Append(lex.p)
@@ -4922,7 +4942,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st7
 	tr251:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st130
 	st130:
@@ -4977,7 +4997,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr248
 	tr252:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st131
 	st131:
@@ -5122,7 +5142,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st11
 	tr20:
-		// line scanner/scanner.rl:50
+		// line scanner/scanner.rl:49
 
 		if lex.data[lex.p] == '\n' {
 			lex.NewLines.Append(lex.p)
@@ -6044,7 +6064,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:176
+		// line scanner/scanner.rl:175
 		lex.act = 10
 		goto st143
 	st143:
@@ -6093,7 +6113,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:176
+		// line scanner/scanner.rl:175
 		lex.act = 10
 		goto st144
 	st144:
@@ -6139,7 +6159,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr243
 	tr92:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st71
 	st71:
@@ -6156,7 +6176,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st71
 	tr93:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st72
 	st72:
@@ -6173,7 +6193,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr92
 	tr94:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st73
 	st73:
@@ -6195,7 +6215,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:187
+		// line scanner/scanner.rl:186
 		lex.act = 12
 		goto st146
 	st146:
@@ -6226,7 +6246,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:176
+		// line scanner/scanner.rl:175
 		lex.act = 10
 		goto st147
 	st147:
@@ -6249,7 +6269,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:187
+		// line scanner/scanner.rl:186
 		lex.act = 12
 		goto st148
 	st148:
@@ -6294,7 +6314,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:177
+		// line scanner/scanner.rl:176
 		lex.act = 11
 		goto st149
 	st149:
@@ -6332,7 +6352,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:202
+		// line scanner/scanner.rl:201
 		lex.act = 13
 		goto st150
 	st150:
@@ -6392,7 +6412,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr243
 	tr104:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st77
 	st77:
@@ -6416,7 +6436,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr22
 	tr105:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st78
 	st78:
@@ -6440,7 +6460,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr22
 	tr106:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st79
 	st79:
@@ -6454,7 +6474,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr22
 	tr107:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st80
 	st80:
@@ -6518,7 +6538,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:317
+		// line scanner/scanner.rl:316
 		lex.act = 118
 		goto st156
 	st156:
@@ -6586,7 +6606,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr115
 	tr115:
-		// line scanner/scanner.rl:47
+		// line scanner/scanner.rl:46
 		lblStart = lex.p
 		goto st84
 	st84:
@@ -6620,7 +6640,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st84
 	tr116:
-		// line scanner/scanner.rl:48
+		// line scanner/scanner.rl:47
 		lblEnd = lex.p
 		goto st85
 	st85:
@@ -6637,7 +6657,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr11
 	tr122:
-		// line scanner/scanner.rl:48
+		// line scanner/scanner.rl:47
 		lblEnd = lex.p
 		goto st157
 	st157:
@@ -6648,7 +6668,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line scanner/scanner.go:5509
 		goto tr289
 	tr123:
-		// line scanner/scanner.rl:48
+		// line scanner/scanner.rl:47
 		lblEnd = lex.p
 		goto st86
 	st86:
@@ -6683,7 +6703,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr120
 	tr120:
-		// line scanner/scanner.rl:47
+		// line scanner/scanner.rl:46
 		lblStart = lex.p
 		goto st88
 	st88:
@@ -6717,7 +6737,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st88
 	tr114:
-		// line scanner/scanner.rl:47
+		// line scanner/scanner.rl:46
 		lblStart = lex.p
 		goto st89
 	st89:
@@ -6904,490 +6924,490 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:357
+		// line scanner/scanner.rl:360
 		lex.act = 135
 		goto st168
 	tr311:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:215
+		// line scanner/scanner.rl:214
 		lex.act = 16
 		goto st168
 	tr317:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:213
+		// line scanner/scanner.rl:212
 		lex.act = 14
 		goto st168
 	tr318:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:286
+		// line scanner/scanner.rl:285
 		lex.act = 87
 		goto st168
 	tr321:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:214
+		// line scanner/scanner.rl:213
 		lex.act = 15
 		goto st168
 	tr326:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:216
+		// line scanner/scanner.rl:215
 		lex.act = 17
 		goto st168
 	tr338:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:217
+		// line scanner/scanner.rl:216
 		lex.act = 18
 		goto st168
 	tr339:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:218
+		// line scanner/scanner.rl:217
 		lex.act = 19
 		goto st168
 	tr341:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:219
+		// line scanner/scanner.rl:218
 		lex.act = 20
 		goto st168
 	tr348:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:244
+		// line scanner/scanner.rl:243
 		lex.act = 45
 		goto st168
 	tr352:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:220
+		// line scanner/scanner.rl:219
 		lex.act = 21
 		goto st168
 	tr354:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:221
+		// line scanner/scanner.rl:220
 		lex.act = 22
 		goto st168
 	tr358:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:222
+		// line scanner/scanner.rl:221
 		lex.act = 23
 		goto st168
 	tr362:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:223
+		// line scanner/scanner.rl:222
 		lex.act = 24
 		goto st168
 	tr365:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:226
+		// line scanner/scanner.rl:225
 		lex.act = 27
 		goto st168
 	tr371:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:224
+		// line scanner/scanner.rl:223
 		lex.act = 25
 		goto st168
 	tr375:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:225
+		// line scanner/scanner.rl:224
 		lex.act = 26
 		goto st168
 	tr376:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:238
+		// line scanner/scanner.rl:237
 		lex.act = 39
 		goto st168
 	tr384:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:227
+		// line scanner/scanner.rl:226
 		lex.act = 28
 		goto st168
 	tr389:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:229
+		// line scanner/scanner.rl:228
 		lex.act = 30
 		goto st168
 	tr392:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:230
+		// line scanner/scanner.rl:229
 		lex.act = 31
 		goto st168
 	tr404:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:231
+		// line scanner/scanner.rl:230
 		lex.act = 32
 		goto st168
 	tr411:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:233
+		// line scanner/scanner.rl:232
 		lex.act = 34
 		goto st168
 	tr412:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:234
+		// line scanner/scanner.rl:233
 		lex.act = 35
 		goto st168
 	tr417:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:235
+		// line scanner/scanner.rl:234
 		lex.act = 36
 		goto st168
 	tr421:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:236
+		// line scanner/scanner.rl:235
 		lex.act = 37
 		goto st168
 	tr423:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:237
+		// line scanner/scanner.rl:236
 		lex.act = 38
 		goto st168
 	tr429:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:239
+		// line scanner/scanner.rl:238
 		lex.act = 40
 		goto st168
 	tr431:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:245
+		// line scanner/scanner.rl:244
 		lex.act = 46
 		goto st168
 	tr438:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:241
+		// line scanner/scanner.rl:240
 		lex.act = 42
 		goto st168
 	tr444:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:243
+		// line scanner/scanner.rl:242
 		lex.act = 44
 		goto st168
 	tr450:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:246
+		// line scanner/scanner.rl:245
 		lex.act = 47
 		goto st168
 	tr452:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:247
+		// line scanner/scanner.rl:246
 		lex.act = 48
 		goto st168
 	tr453:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:248
+		// line scanner/scanner.rl:247
 		lex.act = 49
 		goto st168
 	tr464:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:250
+		// line scanner/scanner.rl:249
 		lex.act = 51
 		goto st168
 	tr477:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:273
+		// line scanner/scanner.rl:272
 		lex.act = 74
 		goto st168
 	tr485:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:251
+		// line scanner/scanner.rl:250
 		lex.act = 52
 		goto st168
 	tr489:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:252
+		// line scanner/scanner.rl:251
 		lex.act = 53
 		goto st168
 	tr495:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:253
+		// line scanner/scanner.rl:252
 		lex.act = 54
 		goto st168
 	tr498:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:249
+		// line scanner/scanner.rl:248
 		lex.act = 50
 		goto st168
 	tr501:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:254
+		// line scanner/scanner.rl:253
 		lex.act = 55
 		goto st168
 	tr510:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:255
+		// line scanner/scanner.rl:254
 		lex.act = 56
 		goto st168
 	tr511:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:285
+		// line scanner/scanner.rl:284
 		lex.act = 86
 		goto st168
 	tr512:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:287
+		// line scanner/scanner.rl:286
 		lex.act = 88
 		goto st168
 	tr519:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:258
+		// line scanner/scanner.rl:257
 		lex.act = 59
 		goto st168
 	tr522:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:256
+		// line scanner/scanner.rl:255
 		lex.act = 57
 		goto st168
 	tr528:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:259
+		// line scanner/scanner.rl:258
 		lex.act = 60
 		goto st168
 	tr532:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:257
+		// line scanner/scanner.rl:256
 		lex.act = 58
 		goto st168
 	tr545:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:275
+		// line scanner/scanner.rl:274
 		lex.act = 76
 		goto st168
 	tr548:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:260
+		// line scanner/scanner.rl:259
 		lex.act = 61
 		goto st168
 	tr554:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:261
+		// line scanner/scanner.rl:260
 		lex.act = 62
 		goto st168
 	tr558:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:262
+		// line scanner/scanner.rl:261
 		lex.act = 63
 		goto st168
 	tr563:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:263
+		// line scanner/scanner.rl:262
 		lex.act = 64
 		goto st168
 	tr565:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:265
+		// line scanner/scanner.rl:264
 		lex.act = 66
 		goto st168
 	tr567:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:264
+		// line scanner/scanner.rl:263
 		lex.act = 65
 		goto st168
 	tr572:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:266
+		// line scanner/scanner.rl:265
 		lex.act = 67
 		goto st168
 	tr573:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:267
+		// line scanner/scanner.rl:266
 		lex.act = 68
 		goto st168
 	tr575:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:268
+		// line scanner/scanner.rl:267
 		lex.act = 69
 		goto st168
 	tr579:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:269
+		// line scanner/scanner.rl:268
 		lex.act = 70
 		goto st168
 	tr581:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:288
+		// line scanner/scanner.rl:287
 		lex.act = 89
 		goto st168
 	tr590:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:270
+		// line scanner/scanner.rl:269
 		lex.act = 71
 		goto st168
 	tr606:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:276
+		// line scanner/scanner.rl:275
 		lex.act = 77
 		goto st168
 	tr610:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:277
+		// line scanner/scanner.rl:276
 		lex.act = 78
 		goto st168
 	tr616:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:278
+		// line scanner/scanner.rl:277
 		lex.act = 79
 		goto st168
 	tr624:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:279
+		// line scanner/scanner.rl:278
 		lex.act = 80
 		goto st168
 	tr636:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:284
+		// line scanner/scanner.rl:283
 		lex.act = 85
 		goto st168
 	tr641:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:280
+		// line scanner/scanner.rl:279
 		lex.act = 81
 		goto st168
 	tr648:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:282
+		// line scanner/scanner.rl:281
 		lex.act = 83
 		goto st168
 	tr658:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:281
+		// line scanner/scanner.rl:280
 		lex.act = 82
 		goto st168
 	tr664:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:283
+		// line scanner/scanner.rl:282
 		lex.act = 84
 		goto st168
 	st168:
@@ -7731,7 +7751,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:357
+		// line scanner/scanner.rl:360
 		lex.act = 135
 		goto st179
 	st179:
@@ -14776,7 +14796,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr215
 	tr134:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st93
 	st93:
@@ -14802,7 +14822,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr129
 	tr135:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st94
 	st94:
@@ -14828,7 +14848,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr129
 	tr136:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st95
 	st95:
@@ -14842,7 +14862,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr129
 	tr137:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st96
 	st96:
@@ -16954,14 +16974,14 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr243
 	tr141:
-		// line scanner/scanner.rl:391
+		// line scanner/scanner.rl:397
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.WhiteSpaceType, lex.ts, lex.te)
 		}
 		goto st470
 	tr667:
-		// line scanner/scanner.rl:394
+		// line scanner/scanner.rl:400
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -16971,7 +16991,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st470
 	tr672:
-		// line scanner/scanner.rl:391
+		// line scanner/scanner.rl:397
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -16979,9 +16999,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st470
 	tr674:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:391
+		// line scanner/scanner.rl:397
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -16989,7 +17009,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st470
 	tr678:
-		// line scanner/scanner.rl:394
+		// line scanner/scanner.rl:400
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17000,7 +17020,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st470
 	tr679:
-		// line scanner/scanner.rl:392
+		// line scanner/scanner.rl:398
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -17014,7 +17034,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st470
 	tr680:
 		lex.cs = 470
-		// line scanner/scanner.rl:393
+		// line scanner/scanner.rl:399
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17083,7 +17103,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st471
 	st471:
@@ -17113,7 +17133,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st472
 	st472:
@@ -17135,7 +17155,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr674
 	tr677:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st99
 	st99:
@@ -17219,9 +17239,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr684:
 		lex.cs = 476
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:398
+		// line scanner/scanner.rl:404
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17302,16 +17322,16 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:398
+		// line scanner/scanner.rl:404
 		lex.act = 146
 		goto st477
 	tr685:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:398
+		// line scanner/scanner.rl:404
 		lex.act = 146
 		goto st477
 	st477:
@@ -17366,7 +17386,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr683
 	tr686:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st478
 	st478:
@@ -17421,7 +17441,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr684
 	tr143:
-		// line scanner/scanner.rl:407
+		// line scanner/scanner.rl:413
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -17432,7 +17452,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st479
 	tr693:
-		// line scanner/scanner.rl:409
+		// line scanner/scanner.rl:415
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17448,7 +17468,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st479
 	tr694:
-		// line scanner/scanner.rl:408
+		// line scanner/scanner.rl:414
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -17498,9 +17518,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr696:
 		lex.cs = 479
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:410
+		// line scanner/scanner.rl:416
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17518,7 +17538,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr700:
 		lex.cs = 479
-		// line scanner/scanner.rl:410
+		// line scanner/scanner.rl:416
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17622,30 +17642,30 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:410
+		// line scanner/scanner.rl:416
 		lex.act = 150
 		goto st481
 	tr697:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:410
+		// line scanner/scanner.rl:416
 		lex.act = 150
 		goto st481
 	tr699:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:408
+		// line scanner/scanner.rl:414
 		lex.act = 148
 		goto st481
 	tr701:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:407
+		// line scanner/scanner.rl:413
 		lex.act = 147
 		goto st481
 	st481:
@@ -17700,7 +17720,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr695
 	tr698:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st482
 	st482:
@@ -17865,7 +17885,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr700
 	tr145:
-		// line scanner/scanner.rl:424
+		// line scanner/scanner.rl:430
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(2)
@@ -17880,7 +17900,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st485
 	tr146:
-		// line scanner/scanner.rl:423
+		// line scanner/scanner.rl:429
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -17890,7 +17910,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st485
 	tr147:
-		// line scanner/scanner.rl:422
+		// line scanner/scanner.rl:428
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -17902,7 +17922,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st485
 	tr703:
 		lex.cs = 485
-		// line scanner/scanner.rl:425
+		// line scanner/scanner.rl:431
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -17974,9 +17994,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 
 		goto _again
 	tr711:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:426
+		// line scanner/scanner.rl:432
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -17990,7 +18010,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st485
 	tr714:
-		// line scanner/scanner.rl:426
+		// line scanner/scanner.rl:432
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -18110,44 +18130,44 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:426
+		// line scanner/scanner.rl:432
 		lex.act = 155
 		goto st486
 	tr708:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:425
+		// line scanner/scanner.rl:431
 		lex.act = 154
 		goto st486
 	tr712:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:426
+		// line scanner/scanner.rl:432
 		lex.act = 155
 		goto st486
 	tr715:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:424
+		// line scanner/scanner.rl:430
 		lex.act = 153
 		goto st486
 	tr716:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:423
+		// line scanner/scanner.rl:429
 		lex.act = 152
 		goto st486
 	tr717:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:422
+		// line scanner/scanner.rl:428
 		lex.act = 151
 		goto st486
 	st486:
@@ -18202,7 +18222,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr710
 	tr713:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st487
 	st487:
@@ -18408,7 +18428,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr714
 	tr148:
-		// line scanner/scanner.rl:436
+		// line scanner/scanner.rl:442
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(2)
@@ -18423,7 +18443,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st490
 	tr149:
-		// line scanner/scanner.rl:435
+		// line scanner/scanner.rl:441
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -18433,7 +18453,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st490
 	tr150:
-		// line scanner/scanner.rl:434
+		// line scanner/scanner.rl:440
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -18445,7 +18465,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st490
 	tr718:
 		lex.cs = 490
-		// line scanner/scanner.rl:437
+		// line scanner/scanner.rl:443
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -18508,6 +18528,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 
 				lex.setTokenPosition(token)
 				tok = T_ENCAPSED_AND_WHITESPACE
+				if lex.te == lex.pe {
+					lex.errorUnterminatedString()
+				}
 				{
 					(lex.p)++
 					goto _out
@@ -18517,14 +18540,17 @@ func (lex *Lexer) Lex(lval Lval) int {
 
 		goto _again
 	tr727:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:438
+		// line scanner/scanner.rl:444
 		lex.te = (lex.p)
 		(lex.p)--
 		{
 			lex.setTokenPosition(token)
 			tok = T_ENCAPSED_AND_WHITESPACE
+			if lex.te == lex.pe {
+				lex.errorUnterminatedString()
+			}
 			{
 				(lex.p)++
 				lex.cs = 490
@@ -18533,12 +18559,15 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st490
 	tr730:
-		// line scanner/scanner.rl:438
+		// line scanner/scanner.rl:444
 		lex.te = (lex.p)
 		(lex.p)--
 		{
 			lex.setTokenPosition(token)
 			tok = T_ENCAPSED_AND_WHITESPACE
+			if lex.te == lex.pe {
+				lex.errorUnterminatedString()
+			}
 			{
 				(lex.p)++
 				lex.cs = 490
@@ -18653,44 +18682,44 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:438
+		// line scanner/scanner.rl:444
 		lex.act = 160
 		goto st491
 	tr723:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:437
+		// line scanner/scanner.rl:443
 		lex.act = 159
 		goto st491
 	tr728:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:438
+		// line scanner/scanner.rl:444
 		lex.act = 160
 		goto st491
 	tr731:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:436
+		// line scanner/scanner.rl:442
 		lex.act = 158
 		goto st491
 	tr732:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:435
+		// line scanner/scanner.rl:441
 		lex.act = 157
 		goto st491
 	tr733:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:434
+		// line scanner/scanner.rl:440
 		lex.act = 156
 		goto st491
 	st491:
@@ -18745,7 +18774,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr726
 	tr729:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st492
 	st492:
@@ -18952,7 +18981,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto tr730
 	tr735:
 		lex.cs = 495
-		// line scanner/scanner.rl:446
+		// line scanner/scanner.rl:455
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19020,7 +19049,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st496
 	tr151:
-		// line scanner/scanner.rl:465
+		// line scanner/scanner.rl:474
 		(lex.p) = (lex.te) - 1
 		{
 			lex.ungetCnt(1)
@@ -19032,7 +19061,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr152:
-		// line scanner/scanner.rl:462
+		// line scanner/scanner.rl:471
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19046,7 +19075,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr736:
-		// line scanner/scanner.rl:465
+		// line scanner/scanner.rl:474
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19058,7 +19087,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr740:
-		// line scanner/scanner.rl:464
+		// line scanner/scanner.rl:473
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -19068,7 +19097,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr741:
-		// line scanner/scanner.rl:465
+		// line scanner/scanner.rl:474
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19081,7 +19110,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr743:
-		// line scanner/scanner.rl:461
+		// line scanner/scanner.rl:470
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19095,7 +19124,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st497
 	tr745:
-		// line scanner/scanner.rl:463
+		// line scanner/scanner.rl:472
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19253,7 +19282,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st501
 	tr153:
-		// line scanner/scanner.rl:469
+		// line scanner/scanner.rl:478
 		(lex.p) = (lex.te) - 1
 		{
 			lex.setTokenPosition(token)
@@ -19266,15 +19295,14 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr746:
-		// line scanner/scanner.rl:475
+		// line scanner/scanner.rl:484
 		lex.te = (lex.p) + 1
 		{
-			c := lex.data[lex.p]
-			lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+			lex.errorUnexpectedChars()
 		}
 		goto st502
 	tr747:
-		// line scanner/scanner.rl:472
+		// line scanner/scanner.rl:481
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -19284,7 +19312,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr750:
-		// line scanner/scanner.rl:473
+		// line scanner/scanner.rl:482
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -19297,7 +19325,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr754:
-		// line scanner/scanner.rl:474
+		// line scanner/scanner.rl:483
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -19307,9 +19335,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr755:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:472
+		// line scanner/scanner.rl:481
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19320,16 +19348,15 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr756:
-		// line scanner/scanner.rl:475
+		// line scanner/scanner.rl:484
 		lex.te = (lex.p)
 		(lex.p)--
 		{
-			c := lex.data[lex.p]
-			lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c))
+			lex.errorUnexpectedChars()
 		}
 		goto st502
 	tr757:
-		// line scanner/scanner.rl:473
+		// line scanner/scanner.rl:482
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19343,7 +19370,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr759:
-		// line scanner/scanner.rl:470
+		// line scanner/scanner.rl:479
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19357,7 +19384,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr760:
-		// line scanner/scanner.rl:469
+		// line scanner/scanner.rl:478
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19371,7 +19398,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st502
 	tr764:
-		// line scanner/scanner.rl:471
+		// line scanner/scanner.rl:480
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19672,7 +19699,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st511
 	tr157:
 		lex.cs = 512
-		// line scanner/scanner.rl:483
+		// line scanner/scanner.rl:489
 		(lex.p) = (lex.te) - 1
 		{
 			lex.ungetCnt(1)
@@ -19681,7 +19708,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr159:
 		lex.cs = 512
-		// line scanner/scanner.rl:482
+		// line scanner/scanner.rl:488
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19696,7 +19723,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr765:
 		lex.cs = 512
-		// line scanner/scanner.rl:483
+		// line scanner/scanner.rl:489
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19705,7 +19732,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr767:
 		lex.cs = 512
-		// line scanner/scanner.rl:483
+		// line scanner/scanner.rl:489
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19810,7 +19837,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st109
 	tr160:
-		// line scanner/scanner.rl:487
+		// line scanner/scanner.rl:493
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.WhiteSpaceType, lex.ts, lex.te)
@@ -19818,7 +19845,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st514
 	tr768:
 		lex.cs = 514
-		// line scanner/scanner.rl:489
+		// line scanner/scanner.rl:495
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19827,7 +19854,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr771:
 		lex.cs = 514
-		// line scanner/scanner.rl:488
+		// line scanner/scanner.rl:494
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -19840,7 +19867,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr772:
-		// line scanner/scanner.rl:487
+		// line scanner/scanner.rl:493
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19848,9 +19875,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st514
 	tr774:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:487
+		// line scanner/scanner.rl:493
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19859,7 +19886,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st514
 	tr778:
 		lex.cs = 514
-		// line scanner/scanner.rl:489
+		// line scanner/scanner.rl:495
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -19902,7 +19929,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st515
 	st515:
@@ -19932,7 +19959,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st516
 	st516:
@@ -19954,7 +19981,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr774
 	tr777:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st110
 	st110:
@@ -19977,7 +20004,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr778
 	tr162:
-		// line scanner/scanner.rl:493
+		// line scanner/scanner.rl:499
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.WhiteSpaceType, lex.ts, lex.te)
@@ -19985,7 +20012,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st518
 	tr779:
 		lex.cs = 518
-		// line scanner/scanner.rl:495
+		// line scanner/scanner.rl:501
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -19994,7 +20021,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr782:
 		lex.cs = 518
-		// line scanner/scanner.rl:494
+		// line scanner/scanner.rl:500
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -20007,7 +20034,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr783:
-		// line scanner/scanner.rl:493
+		// line scanner/scanner.rl:499
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20015,9 +20042,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st518
 	tr785:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:493
+		// line scanner/scanner.rl:499
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20026,7 +20053,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st518
 	tr789:
 		lex.cs = 518
-		// line scanner/scanner.rl:495
+		// line scanner/scanner.rl:501
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20069,7 +20096,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st519
 	st519:
@@ -20099,7 +20126,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st520
 	st520:
@@ -20121,7 +20148,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr785
 	tr788:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st111
 	st111:
@@ -20144,7 +20171,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr789
 	tr164:
-		// line scanner/scanner.rl:499
+		// line scanner/scanner.rl:505
 		(lex.p) = (lex.te) - 1
 		{
 			lex.addFreeFloating(freefloating.WhiteSpaceType, lex.ts, lex.te)
@@ -20152,7 +20179,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st522
 	tr790:
 		lex.cs = 522
-		// line scanner/scanner.rl:501
+		// line scanner/scanner.rl:507
 		lex.te = (lex.p) + 1
 		{
 			lex.ungetCnt(1)
@@ -20161,7 +20188,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto _again
 	tr793:
 		lex.cs = 522
-		// line scanner/scanner.rl:500
+		// line scanner/scanner.rl:506
 		lex.te = (lex.p) + 1
 		{
 			lex.setTokenPosition(token)
@@ -20174,7 +20201,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto _again
 	tr794:
-		// line scanner/scanner.rl:499
+		// line scanner/scanner.rl:505
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20182,9 +20209,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto st522
 	tr796:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:499
+		// line scanner/scanner.rl:505
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20193,7 +20220,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		goto st522
 	tr800:
 		lex.cs = 522
-		// line scanner/scanner.rl:501
+		// line scanner/scanner.rl:507
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20236,7 +20263,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st523
 	st523:
@@ -20266,7 +20293,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st524
 	st524:
@@ -20288,7 +20315,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr796
 	tr799:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st112
 	st112:
@@ -20328,9 +20355,9 @@ func (lex *Lexer) Lex(lval Lval) int {
 
 		goto st526
 	tr804:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:505
+		// line scanner/scanner.rl:511
 		lex.te = (lex.p)
 		(lex.p)--
 		{
@@ -20360,16 +20387,16 @@ func (lex *Lexer) Lex(lval Lval) int {
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:505
+		// line scanner/scanner.rl:511
 		lex.act = 186
 		goto st527
 	tr805:
 		// line NONE:1
 		lex.te = (lex.p) + 1
 
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
-		// line scanner/scanner.rl:505
+		// line scanner/scanner.rl:511
 		lex.act = 186
 		goto st527
 	st527:
@@ -20383,7 +20410,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 		goto tr801
 	tr806:
-		// line scanner/scanner.rl:66
+		// line scanner/scanner.rl:65
 		lex.NewLines.Append(lex.p)
 		goto st528
 	st528:
@@ -23009,7 +23036,7 @@ func (lex *Lexer) Lex(lval Lval) int {
 		}
 	}
 
-	// line scanner/scanner.rl:509
+	// line scanner/scanner.rl:515
 
 	token.FreeFloating = lex.FreeFloating
 	token.Value = string(lex.data[lex.ts:lex.te])
@@ -1,7 +1,6 @@
 package scanner
 
 import (
-    "fmt"
     "strconv"
     "strings"
 
@@ -242,7 +241,7 @@ func (lex *Lexer) Lex(lval Lval) int {
             'for'i                            => {lex.setTokenPosition(token); tok = T_FOR; fbreak;};
             'foreach'i                        => {lex.setTokenPosition(token); tok = T_FOREACH; fbreak;};
             'function'i | 'cfunction'i        => {lex.setTokenPosition(token); tok = T_FUNCTION; fbreak;};
-            'fn'i                             => {lex.setTokenPosition(token); tok = T_FN; fbreak;};
+            'fn'i                             => {lex.setTokenPosition(token); tok = lex.keywordSince(T_FN, "7.4"); fbreak;};
             'global'i                         => {lex.setTokenPosition(token); tok = T_GLOBAL; fbreak;};
             'goto'i                           => {lex.setTokenPosition(token); tok = T_GOTO; fbreak;};
             'if'i                             => {lex.setTokenPosition(token); tok = T_IF; fbreak;};
@@ -344,11 +343,15 @@ func (lex *Lexer) Lex(lval Lval) int {
             };
 
             operators => {
-                // rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
-                // tok = TokenID(Rune2Class(rune));
-                lex.setTokenPosition(token);
-                tok = TokenID(int(lex.data[lex.ts]));
-                fbreak;
+                if lex.isUnterminatedComment() {
+                    lex.errorUnterminatedComment()
+                } else {
+                    // rune, _ := utf8.DecodeRune(lex.data[lex.ts:lex.te]);
+                    // tok = TokenID(Rune2Class(rune));
+                    lex.setTokenPosition(token);
+                    tok = TokenID(int(lex.data[lex.ts]));
+                    fbreak;
+                }
             };
 
             "{"          => { lex.setTokenPosition(token); tok = TokenID(int('{')); lex.call(ftargs, fentry(php)); goto _out; };
@@ -382,8 +385,11 @@ func (lex *Lexer) Lex(lval Lval) int {
             '"' => {lex.setTokenPosition(token); tok = TokenID(int('"')); fnext template_string; fbreak;};
 
             any_line => {
-                c := lex.data[lex.p]
-                lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c));
+                if lex.data[lex.ts] == '\'' {
+                    lex.errorUnterminatedString()
+                } else {
+                    lex.errorUnexpectedChars()
+                }
             };
         *|;
 
@@ -438,6 +444,9 @@ func (lex *Lexer) Lex(lval Lval) int {
             any_line* when is_not_string_end_or_var => {
                 lex.setTokenPosition(token);
                 tok = T_ENCAPSED_AND_WHITESPACE;
+                if lex.te == lex.pe {
+                    lex.errorUnterminatedString()
+                }
                 fbreak;
             };
         *|;
@@ -472,10 +481,7 @@ func (lex *Lexer) Lex(lval Lval) int {
             whitespace_line | [\\'#] => {lex.setTokenPosition(token); tok = T_ENCAPSED_AND_WHITESPACE; lex.ret(2); goto _out;};
             operators > (svi, 1)     => {lex.setTokenPosition(token); tok = TokenID(int(lex.data[lex.ts])); fbreak;};
             ']'       > (svi, 2)     => {lex.setTokenPosition(token); tok = TokenID(int(']')); lex.ret(2); goto _out;};
-            any_line => {
-                c := lex.data[lex.p]
-                lex.Error(fmt.Sprintf("WARNING: Unexpected character in input: '%c' (ASCII=%d)", c, c));
-            };
+            any_line => {lex.errorUnexpectedChars()};
         *|;
 
         string_var_name := |*
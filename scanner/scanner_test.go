@@ -1077,6 +1077,37 @@ func TestInlineHtmlNopTokens(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestInlineHtmlAfterCloseTagNewLine(t *testing.T) {
+	src := "<?php echo 1; ?>\nText<?php echo 2; ?>\n\nText"
+
+	expected := []string{
+		"echo",
+		"1",
+		"; ?>\n",
+		"Text",
+
+		"echo",
+		"2",
+		"; ?>\n",
+		"\nText",
+	}
+
+	lexer := NewLexer([]byte(src))
+	lv := &lval{}
+	actual := []string{}
+
+	for {
+		token := lexer.Lex(lv)
+		if token == 0 {
+			break
+		}
+
+		actual = append(actual, lv.Tkn.Value)
+	}
+
+	assert.DeepEqual(t, expected, actual)
+}
+
 func TestStringTokensAfterVariable(t *testing.T) {
 	src := `<?php "test \"$var\""`
 
@@ -1574,6 +1605,23 @@ func TestYieldFromTokens(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestFnTokenDependsOnVersion(t *testing.T) {
+	src := `<?php fn`
+
+	lexer := NewLexer([]byte(src))
+	lexer.PHPVersion = "7.3"
+	lv := &lval{}
+
+	actual := TokenID(lexer.Lex(lv)).String()
+	assert.Equal(t, T_STRING.String(), actual)
+
+	lexer = NewLexer([]byte(src))
+	lexer.PHPVersion = "7.4"
+
+	actual = TokenID(lexer.Lex(lv)).String()
+	assert.Equal(t, T_FN.String(), actual)
+}
+
 func TestVarNameByteChars(t *testing.T) {
 	src := "<?php $\x80 $\xff"
 
@@ -1626,6 +1674,27 @@ func TestIgnoreControllCharacters(t *testing.T) {
 	assert.DeepEqual(t, expected, actual)
 }
 
+func TestControllCharactersRunReportedOnce(t *testing.T) {
+	src := "<?php \001\002\003 echo $b;"
+
+	lexer := NewLexer([]byte(src))
+	lv := &lval{}
+
+	expected := "echo"
+	lexer.Lex(lv)
+	actual := lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	expected = "$b"
+	lexer.Lex(lv)
+	actual = lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	assert.Equal(t, 1, len(lexer.Errors))
+	assert.Equal(t, `WARNING: Unexpected characters in input: "\x01\x02\x03"`, lexer.Errors[0].Msg)
+	assert.DeepEqual(t, &position.Position{StartLine: 1, EndLine: 1, StartPos: 6, EndPos: 9}, lexer.Errors[0].Pos)
+}
+
 func TestIgnoreControllCharactersAtStringVarOffset(t *testing.T) {
 	src := "<?php \"$a[test\004]\";"
 
@@ -1657,3 +1726,129 @@ func TestIgnoreControllCharactersAtStringVarOffset(t *testing.T) {
 	actual = lv.Tkn.Value
 	assert.DeepEqual(t, expected, actual)
 }
+
+func TestControllCharactersRunAtStringVarOffsetReportedOnce(t *testing.T) {
+	src := "<?php \"$a[test\004\005\006]\";"
+
+	lexer := NewLexer([]byte(src))
+	lv := &lval{}
+
+	expected := "\""
+	lexer.Lex(lv)
+	actual := lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	expected = "$a"
+	lexer.Lex(lv)
+	actual = lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	expected = "["
+	lexer.Lex(lv)
+	actual = lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	expected = "test"
+	lexer.Lex(lv)
+	actual = lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	expected = "]"
+	lexer.Lex(lv)
+	actual = lv.Tkn.Value
+	assert.DeepEqual(t, expected, actual)
+
+	assert.Equal(t, 1, len(lexer.Errors))
+	assert.Equal(t, `WARNING: Unexpected characters in input: "\x04\x05\x06"`, lexer.Errors[0].Msg)
+	assert.DeepEqual(t, &position.Position{StartLine: 1, EndLine: 1, StartPos: 14, EndPos: 17}, lexer.Errors[0].Pos)
+}
+
+func TestUnterminatedComment(t *testing.T) {
+	src := "<?php $a; /* foo\nbar"
+
+	lexer := NewLexer([]byte(src))
+	lexer.WithFreeFloating = true
+	lv := &lval{}
+
+	expected := []string{"$a", ";"}
+	actual := []string{}
+
+	for {
+		token := lexer.Lex(lv)
+		if token == 0 {
+			break
+		}
+
+		actual = append(actual, lv.Tkn.Value)
+	}
+
+	assert.DeepEqual(t, expected, actual)
+
+	expectedFreeFloating := []freefloating.String{
+		{
+			StringType: freefloating.WhiteSpaceType,
+			Value:      " ",
+			Position:   position.NewPosition(1, 1, 9, 10),
+		},
+		{
+			StringType: freefloating.CommentType,
+			Value:      "/* foo\nbar",
+			Position:   position.NewPosition(1, 2, 10, 20),
+		},
+	}
+	assert.DeepEqual(t, expectedFreeFloating, lv.Tkn.FreeFloating)
+
+	assert.Equal(t, 1, len(lexer.Errors))
+	assert.Equal(t, "WARNING: Unterminated comment starting line 1", lexer.Errors[0].Msg)
+	assert.DeepEqual(t, &position.Position{StartLine: 1, EndLine: 2, StartPos: 10, EndPos: 20}, lexer.Errors[0].Pos)
+}
+
+func TestUnterminatedSingleQuotedString(t *testing.T) {
+	src := "<?php echo 'abc"
+
+	lexer := NewLexer([]byte(src))
+	lv := &lval{}
+
+	expected := []string{"echo"}
+	actual := []string{}
+
+	for {
+		token := lexer.Lex(lv)
+		if token == 0 {
+			break
+		}
+
+		actual = append(actual, lv.Tkn.Value)
+	}
+
+	assert.DeepEqual(t, expected, actual)
+
+	assert.Equal(t, 1, len(lexer.Errors))
+	assert.Equal(t, "WARNING: Unterminated string", lexer.Errors[0].Msg)
+	assert.DeepEqual(t, &position.Position{StartLine: 1, EndLine: 1, StartPos: 11, EndPos: 15}, lexer.Errors[0].Pos)
+}
+
+func TestUnterminatedDoubleQuotedString(t *testing.T) {
+	src := "<?php echo \"abc"
+
+	lexer := NewLexer([]byte(src))
+	lv := &lval{}
+
+	expected := []string{"echo", "\"", "abc"}
+	actual := []string{}
+
+	for {
+		token := lexer.Lex(lv)
+		if token == 0 {
+			break
+		}
+
+		actual = append(actual, lv.Tkn.Value)
+	}
+
+	assert.DeepEqual(t, expected, actual)
+
+	assert.Equal(t, 1, len(lexer.Errors))
+	assert.Equal(t, "WARNING: Unterminated string", lexer.Errors[0].Msg)
+	assert.DeepEqual(t, &position.Position{StartLine: 1, EndLine: 1, StartPos: 12, EndPos: 15}, lexer.Errors[0].Pos)
+}
@@ -0,0 +1,109 @@
+// Package visitor contains walker.visitor implementations
+package visitor
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/z7zmey/php-parser/freefloating"
+	"github.com/z7zmey/php-parser/node"
+	"github.com/z7zmey/php-parser/position"
+	"github.com/z7zmey/php-parser/walker"
+)
+
+// CommentKind is a syntactic form of a comment
+type CommentKind int
+
+const (
+	LineComment  CommentKind = iota // "// ..."
+	HashComment                     // "# ..."
+	BlockComment                    // "/* ... */"
+	DocComment                      // "/** ... */"
+)
+
+// Comment is a comment collected by CommentCollector
+type Comment struct {
+	Kind     CommentKind
+	Value    string
+	Position *position.Position
+}
+
+// CommentCollector visitor gathers all comments from free-floating strings
+// ordered by their position in source
+//
+// The AST must be parsed with free-floating strings enabled
+type CommentCollector struct {
+	Comments []Comment
+}
+
+// EnterNode is invoked at every node in hierarchy
+func (c *CommentCollector) EnterNode(w walker.Walkable) bool {
+	n, ok := w.(node.Node)
+	if !ok {
+		return true
+	}
+
+	for _, ffStrings := range *n.GetFreeFloating() {
+		for _, ffString := range ffStrings {
+			if ffString.StringType == freefloating.CommentType {
+				c.addComment(ffString)
+			}
+		}
+	}
+
+	return true
+}
+
+func (c *CommentCollector) addComment(s freefloating.String) {
+	i := sort.Search(len(c.Comments), func(i int) bool {
+		return c.Comments[i].Position.StartPos >= s.Position.StartPos
+	})
+
+	// the same comment may be shared by several nodes
+	if i < len(c.Comments) && c.Comments[i].Position.StartPos == s.Position.StartPos {
+		return
+	}
+
+	c.Comments = append(c.Comments, Comment{})
+	copy(c.Comments[i+1:], c.Comments[i:])
+	c.Comments[i] = Comment{
+		Kind:     commentKind(s.Value),
+		Value:    s.Value,
+		Position: s.Position,
+	}
+}
+
+// commentKind detects doc comments the same way the scanner does
+func commentKind(v string) CommentKind {
+	switch {
+	case strings.HasPrefix(v, "#"):
+		return HashComment
+	case strings.HasPrefix(v, "//"):
+		return LineComment
+	case len(v) > 4 && strings.HasPrefix(v, "/**"):
+		return DocComment
+	default:
+		return BlockComment
+	}
+}
+
+// LeaveNode is invoked after node process
+func (c *CommentCollector) LeaveNode(w walker.Walkable) {
+	// do nothing
+}
+
+func (c *CommentCollector) EnterChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *CommentCollector) LeaveChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *CommentCollector) EnterChildList(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *CommentCollector) LeaveChildList(key string, w walker.Walkable) {
+	// do nothing
+}
@@ -0,0 +1,47 @@
+package visitor_test
+
+import (
+	"testing"
+
+	"gotest.tools/assert"
+
+	"github.com/z7zmey/php-parser/php7"
+	"github.com/z7zmey/php-parser/position"
+	"github.com/z7zmey/php-parser/visitor"
+)
+
+func TestCommentCollector(t *testing.T) {
+	src := "<?php\n// line\n# hash\n/* block */\n/** doc */\n$a;"
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.WithFreeFloating()
+	php7parser.Parse()
+
+	collector := &visitor.CommentCollector{}
+	php7parser.GetRootNode().Walk(collector)
+
+	expected := []visitor.Comment{
+		{
+			Kind:     visitor.LineComment,
+			Value:    "// line\n",
+			Position: position.NewPosition(2, 2, 6, 14),
+		},
+		{
+			Kind:     visitor.HashComment,
+			Value:    "# hash\n",
+			Position: position.NewPosition(3, 3, 14, 21),
+		},
+		{
+			Kind:     visitor.BlockComment,
+			Value:    "/* block */",
+			Position: position.NewPosition(4, 4, 21, 32),
+		},
+		{
+			Kind:     visitor.DocComment,
+			Value:    "/** doc */",
+			Position: position.NewPosition(5, 5, 33, 43),
+		},
+	}
+
+	assert.DeepEqual(t, expected, collector.Comments)
+}
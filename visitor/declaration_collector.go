@@ -0,0 +1,109 @@
+// Package visitor contains walker.visitor implementations
+package visitor
+
+import (
+	"github.com/z7zmey/php-parser/node"
+	"github.com/z7zmey/php-parser/node/expr"
+	"github.com/z7zmey/php-parser/node/name"
+	"github.com/z7zmey/php-parser/node/stmt"
+	"github.com/z7zmey/php-parser/walker"
+)
+
+// Declaration kinds
+const (
+	FunctionDeclaration  = "function"
+	ClassDeclaration     = "class"
+	InterfaceDeclaration = "interface"
+	TraitDeclaration     = "trait"
+)
+
+// Declaration describes a named function, class, interface or trait declaration
+type Declaration struct {
+	Kind     string
+	Name     string    // fully qualified name without leading backslash
+	NameNode node.Node // *node.Identifier holding the name position
+	Node     node.Node
+}
+
+// DeclarationCollector visitor collects function, class, interface and trait
+// declarations in source order. Bodies of declarations and closures are not
+// visited, so nested functions and anonymous classes are skipped.
+type DeclarationCollector struct {
+	Namespace    string
+	Declarations []Declaration
+}
+
+// NewDeclarationCollector DeclarationCollector type constructor
+func NewDeclarationCollector() *DeclarationCollector {
+	return &DeclarationCollector{}
+}
+
+// EnterNode is invoked at every node in hierarchy
+func (c *DeclarationCollector) EnterNode(w walker.Walkable) bool {
+	switch n := w.(type) {
+	case *stmt.Namespace:
+		if n.NamespaceName == nil {
+			c.Namespace = ""
+		} else {
+			c.Namespace = concatNameParts(n.NamespaceName.(*name.Name).Parts)
+		}
+
+	case *stmt.Function:
+		c.add(FunctionDeclaration, n.FunctionName, n)
+		return false
+
+	case *stmt.Class:
+		if n.ClassName != nil {
+			c.add(ClassDeclaration, n.ClassName, n)
+		}
+		return false
+
+	case *stmt.Interface:
+		c.add(InterfaceDeclaration, n.InterfaceName, n)
+		return false
+
+	case *stmt.Trait:
+		c.add(TraitDeclaration, n.TraitName, n)
+		return false
+
+	case *expr.Closure, *expr.ArrowFunction:
+		return false
+	}
+
+	return true
+}
+
+func (c *DeclarationCollector) add(kind string, nameNode node.Node, n node.Node) {
+	declName := nameNode.(*node.Identifier).Value
+	if c.Namespace != "" {
+		declName = c.Namespace + "\\" + declName
+	}
+
+	c.Declarations = append(c.Declarations, Declaration{
+		Kind:     kind,
+		Name:     declName,
+		NameNode: nameNode,
+		Node:     n,
+	})
+}
+
+// LeaveNode is invoked after node process
+func (c *DeclarationCollector) LeaveNode(w walker.Walkable) {
+	// do nothing
+}
+
+func (c *DeclarationCollector) EnterChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *DeclarationCollector) LeaveChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *DeclarationCollector) EnterChildList(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (c *DeclarationCollector) LeaveChildList(key string, w walker.Walkable) {
+	// do nothing
+}
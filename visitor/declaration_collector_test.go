@@ -0,0 +1,41 @@
+package visitor_test
+
+import (
+	"testing"
+
+	"gotest.tools/assert"
+
+	"github.com/z7zmey/php-parser/php7"
+	"github.com/z7zmey/php-parser/position"
+	"github.com/z7zmey/php-parser/visitor"
+)
+
+func TestDeclarationCollector(t *testing.T) {
+	src := "<?php\nnamespace Foo;\nfunction bar() { function nested() {} }\nclass Baz { function method() {} }\ninterface Qux {}\nnamespace Other;\ntrait Quux {}\n$a = new class {};\n"
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+
+	collector := visitor.NewDeclarationCollector()
+	php7parser.GetRootNode().Walk(collector)
+
+	type decl struct {
+		Kind     string
+		Name     string
+		Position *position.Position
+	}
+
+	expected := []decl{
+		{visitor.FunctionDeclaration, "Foo\\bar", position.NewPosition(3, 3, 30, 33)},
+		{visitor.ClassDeclaration, "Foo\\Baz", position.NewPosition(4, 4, 67, 70)},
+		{visitor.InterfaceDeclaration, "Foo\\Qux", position.NewPosition(5, 5, 106, 109)},
+		{visitor.TraitDeclaration, "Other\\Quux", position.NewPosition(7, 7, 136, 140)},
+	}
+
+	actual := []decl{}
+	for _, d := range collector.Declarations {
+		actual = append(actual, decl{d.Kind, d.Name, d.NameNode.GetPosition()})
+	}
+
+	assert.DeepEqual(t, expected, actual)
+}
@@ -0,0 +1,69 @@
+// Package visitor contains walker.visitor implementations
+package visitor
+
+import (
+	"github.com/z7zmey/php-parser/node"
+	"github.com/z7zmey/php-parser/walker"
+)
+
+// NodeFinder visitor finds the deepest node whose position contains Offset.
+// The whole tree is visited because a child position is not always inside
+// its parent position (e.g. stmt.Use starts after its UseType).
+type NodeFinder struct {
+	Offset int
+	Node   node.Node
+
+	depth     int
+	nodeDepth int
+}
+
+// NewNodeFinder NodeFinder type constructor
+func NewNodeFinder(offset int) *NodeFinder {
+	return &NodeFinder{
+		Offset: offset,
+	}
+}
+
+// EnterNode is invoked at every node in hierarchy
+func (f *NodeFinder) EnterNode(w walker.Walkable) bool {
+	f.depth++
+
+	n, ok := w.(node.Node)
+	if !ok {
+		return true
+	}
+
+	// nodes without position (e.g. skipped list items) are transparent
+	p := n.GetPosition()
+	if p == nil {
+		return true
+	}
+
+	if p.Contains(f.Offset) && f.depth >= f.nodeDepth {
+		f.Node = n
+		f.nodeDepth = f.depth
+	}
+
+	return true
+}
+
+// LeaveNode is invoked after node process
+func (f *NodeFinder) LeaveNode(w walker.Walkable) {
+	f.depth--
+}
+
+func (f *NodeFinder) EnterChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (f *NodeFinder) LeaveChildNode(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (f *NodeFinder) EnterChildList(key string, w walker.Walkable) {
+	// do nothing
+}
+
+func (f *NodeFinder) LeaveChildList(key string, w walker.Walkable) {
+	// do nothing
+}
@@ -0,0 +1,62 @@
+package visitor_test
+
+import (
+	"testing"
+
+	"gotest.tools/assert"
+
+	"github.com/z7zmey/php-parser/node"
+	"github.com/z7zmey/php-parser/node/expr"
+	"github.com/z7zmey/php-parser/node/stmt"
+	"github.com/z7zmey/php-parser/php7"
+	"github.com/z7zmey/php-parser/visitor"
+)
+
+func TestNodeFinder(t *testing.T) {
+	src := `<? $a->foo($b);`
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	root := php7parser.GetRootNode()
+
+	exprStmt := root.(*node.Root).Stmts[0].(*stmt.Expression)
+	methodCall := exprStmt.Expr.(*expr.MethodCall)
+	argument := methodCall.ArgumentList.Arguments[0].(*node.Argument)
+	argumentName := argument.Expr.(*expr.Variable).VarName
+
+	tests := []struct {
+		offset   int
+		expected node.Node
+	}{
+		{3, methodCall.Variable.(*expr.Variable).VarName},
+		{5, methodCall},
+		{8, methodCall.Method},
+		{10, methodCall.ArgumentList},
+		{12, argumentName},
+		{14, exprStmt},
+		{15, nil},
+	}
+
+	for _, tt := range tests {
+		finder := visitor.NewNodeFinder(tt.offset)
+		root.Walk(finder)
+
+		assert.Equal(t, tt.expected, finder.Node, "offset %d", tt.offset)
+	}
+}
+
+func TestNodeFinderChildOutsideParent(t *testing.T) {
+	src := `<? use Foo\{function foo};`
+
+	php7parser := php7.NewParser([]byte(src), "7.4")
+	php7parser.Parse()
+	root := php7parser.GetRootNode()
+
+	groupUse := root.(*node.Root).Stmts[0].(*stmt.GroupUse)
+	use := groupUse.UseList[0].(*stmt.Use)
+
+	finder := visitor.NewNodeFinder(14)
+	root.Walk(finder)
+
+	assert.Equal(t, use.UseType, finder.Node)
+}